@@ -0,0 +1,112 @@
+use crate::input::InputKind;
+use crate::solution::Part;
+use crate::AocError;
+
+/// The answers file that is checked by the regression tests.
+pub const ANSWERS_FILE: &str = "inputs/answers.txt";
+
+/// The known answer of one part of a day for either the example or the real input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub kind: InputKind,
+    pub answer: String,
+}
+
+/// Answers are stored one per line as `<day> <part> <example|real> <answer>`.
+///
+/// Multi-line answers such as the screen of day 10 are written with `\n` escapes.
+pub fn escape(answer: &str) -> String {
+    answer.replace('\n', "\\n")
+}
+
+fn parse_kind(input: &str) -> Option<InputKind> {
+    match input {
+        "example" => Some(InputKind::Example),
+        "real" => Some(InputKind::Real),
+        _ => None,
+    }
+}
+
+fn parse_answer(input: &str) -> Result<Answer, AocError> {
+    let mut fields = input.splitn(4, ' ');
+    let mut column = 0;
+    let mut next_field = |expected: &str| {
+        let field = fields
+            .next()
+            .ok_or_else(|| AocError::parse(input, expected).at_column(column))?;
+        let field_column = column;
+        column += field.len() + 1;
+        Ok::<_, AocError>((field, field_column))
+    };
+    let (day, day_column) = next_field("a day")?;
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| AocError::parse(input, "a day").at_column(day_column))?;
+    let (part, part_column) = next_field("a part")?;
+    let part = part
+        .parse::<u8>()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| AocError::parse(input, "part 1 or 2").at_column(part_column))?;
+    let (kind, kind_column) = next_field("`example` or `real`")?;
+    let kind = parse_kind(kind)
+        .ok_or_else(|| AocError::parse(input, "`example` or `real`").at_column(kind_column))?;
+    let (answer, _) = next_field("an answer")?;
+    Ok(Answer {
+        day,
+        part,
+        kind,
+        answer: answer.replace("\\n", "\n"),
+    })
+}
+
+/// Parses an answers file, skipping blank lines and `#` comments.
+pub fn parse_answers(lines: &[String]) -> Result<Vec<Answer>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| parse_answer(line).map_err(|err| err.at_line(index)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let lines = [
+            "# day part input answer",
+            "",
+            "5 2 example MCD",
+            "10 2 real \\n#.\\n.#",
+        ]
+        .map(str::to_string);
+        assert_eq!(
+            parse_answers(&lines),
+            Ok(vec![
+                Answer {
+                    day: 5,
+                    part: Part::Two,
+                    kind: InputKind::Example,
+                    answer: "MCD".to_string()
+                },
+                Answer {
+                    day: 10,
+                    part: Part::Two,
+                    kind: InputKind::Real,
+                    answer: "\n#.\n.#".to_string()
+                }
+            ])
+        );
+        assert_eq!(escape("\n#.\n.#"), lines[3][10..]);
+        assert_eq!(
+            parse_answers(&["5 3 real 1".to_string()]).map_err(|err| err.to_string()),
+            Err("line 1 column 3: expected part 1 or 2, found `5 3 real 1`".to_string())
+        );
+        assert!(parse_answers(&["5 1 real".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,425 @@
+use crate::number_theory::ArithmeticError;
+use crate::AocError;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// A balanced numeral system: an odd base whose digits run from `-(BASE / 2)` to `BASE / 2`.
+pub trait NumeralSystem: fmt::Debug + Clone + Copy + PartialEq + Eq + Hash + Default {
+    const BASE: u8;
+    /// One ASCII character per digit, from the lowest digit to the highest.
+    const DIGITS: &'static str;
+    /// Fails to compile for a system without an odd base and one distinct digit per value,
+    /// as soon as a `Balanced` number of it is used; not meant to be overridden.
+    ///
+    /// ```compile_fail
+    /// use utilities::balanced::{Balanced, NumeralSystem};
+    ///
+    /// #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+    /// struct Ambiguous;
+    ///
+    /// impl NumeralSystem for Ambiguous {
+    ///     const BASE: u8 = 3;
+    ///     // `0` would stand for both 0 and 1:
+    ///     const DIGITS: &'static str = "T00";
+    /// }
+    ///
+    /// println!("{}", Balanced::<Ambiguous>::from(1i64));
+    /// ```
+    const CHECK: () = assert!(
+        Self::BASE % 2 == 1
+            && Self::DIGITS.len() == Self::BASE as usize
+            && Self::DIGITS.is_ascii()
+            && distinct_bytes(Self::DIGITS.as_bytes()),
+        "A balanced numeral system needs an odd base and one distinct ASCII digit per value."
+    );
+}
+
+/// Whether no byte occurs twice, which the digits of a numeral system are checked for
+/// while compiling.
+const fn distinct_bytes(bytes: &[u8]) -> bool {
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut j = i + 1;
+        while j < bytes.len() {
+            if bytes[i] == bytes[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Balanced ternary, where `T` stands for -1.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Ternary;
+
+impl NumeralSystem for Ternary {
+    const BASE: u8 = 3;
+    const DIGITS: &'static str = "T01";
+}
+
+/// Balanced base five as written by the elves, with `=` for -2 and `-` for -1.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Quinary;
+
+impl NumeralSystem for Quinary {
+    const BASE: u8 = 5;
+    const DIGITS: &'static str = "=-012";
+}
+
+/// Balanced base nine, where `A` to `D` stand for -1 to -4.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Nonary;
+
+impl NumeralSystem for Nonary {
+    const BASE: u8 = 9;
+    const DIGITS: &'static str = "DCBA01234";
+}
+
+pub type BalancedTernary = Balanced<Ternary>;
+pub type BalancedQuinary = Balanced<Quinary>;
+pub type BalancedNonary = Balanced<Nonary>;
+
+/// A number in the balanced numeral system `S`, which can grow as large as needed.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct Balanced<S> {
+    /// The digits, least significant first and without leading zeros, so that zero has no
+    /// digits at all.
+    digits: Vec<i8>,
+    system: PhantomData<S>,
+}
+
+impl<S: NumeralSystem> Balanced<S> {
+    const HALF: i64 = {
+        let () = S::CHECK;
+        S::BASE as i64 / 2
+    };
+
+    pub fn zero() -> Balanced<S> {
+        Balanced::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    fn digit_value(digit: char) -> Option<i8> {
+        S::DIGITS
+            .chars()
+            .position(|other| other == digit)
+            .map(|position| (position as i64 - Self::HALF) as i8)
+    }
+
+    fn digit_char(value: i8) -> char {
+        S::DIGITS.as_bytes()[(value as i64 + Self::HALF) as usize] as char
+    }
+
+    /// Describes the alphabet for parse errors, e.g. "one of `T`, `0` or `1`".
+    fn expected_digit() -> String {
+        let digits = S::DIGITS
+            .chars()
+            .map(|digit| format!("`{}`", digit))
+            .collect::<Vec<_>>();
+        let (last, rest) = digits.split_last().expect("A base has at least one digit.");
+        format!("one of {} or {}", rest.join(", "), last)
+    }
+
+    /// Splits `value` into its lowest digit and the rest, without overflowing.
+    fn split_digit(value: i128) -> (i8, i128) {
+        let base = S::BASE as i128;
+        let half = Self::HALF as i128;
+        let remainder = value % base;
+        let digit = (remainder + base + half) % base - half;
+        (digit as i8, value / base + (remainder - digit) / base)
+    }
+
+    /// Carries the column sums over until every digit is in range.
+    fn from_column_sums(sums: impl IntoIterator<Item = i64>) -> Balanced<S> {
+        let mut digits = Vec::new();
+        let mut carry = 0;
+        for sum in sums {
+            let (digit, rest) = Self::split_digit((sum + carry) as i128);
+            digits.push(digit);
+            carry = rest as i64;
+        }
+        while carry != 0 {
+            let (digit, rest) = Self::split_digit(carry as i128);
+            digits.push(digit);
+            carry = rest as i64;
+        }
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Balanced {
+            digits,
+            system: PhantomData,
+        }
+    }
+
+    fn add(&self, other: &Balanced<S>) -> Balanced<S> {
+        let length = self.digits.len().max(other.digits.len());
+        let digit = |number: &Balanced<S>, index| *number.digits.get(index).unwrap_or(&0) as i64;
+        Balanced::from_column_sums(
+            (0..length).map(|index| digit(self, index) + digit(other, index)),
+        )
+    }
+
+    fn mul(&self, other: &Balanced<S>) -> Balanced<S> {
+        if self.is_zero() || other.is_zero() {
+            return Balanced::zero();
+        }
+        let mut sums = vec![0; self.digits.len() + other.digits.len() - 1];
+        for (i, left) in self.digits.iter().enumerate() {
+            for (j, right) in other.digits.iter().enumerate() {
+                sums[i + j] += *left as i64 * *right as i64;
+            }
+        }
+        Balanced::from_column_sums(sums)
+    }
+
+    fn neg(&self) -> Balanced<S> {
+        Balanced {
+            digits: self.digits.iter().map(|digit| -digit).collect(),
+            system: PhantomData,
+        }
+    }
+}
+
+impl<S: NumeralSystem> FromStr for Balanced<S> {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Balanced<S>, AocError> {
+        if input.is_empty() {
+            return Err(AocError::parse(input, "a balanced number"));
+        }
+        let digits = input
+            .chars()
+            .enumerate()
+            .map(|(column, digit)| {
+                Self::digit_value(digit)
+                    .ok_or_else(|| AocError::parse(input, Self::expected_digit()).at_column(column))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Balanced::from_column_sums(
+            digits.into_iter().rev().map(i64::from),
+        ))
+    }
+}
+
+impl<S: NumeralSystem> fmt::Display for Balanced<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", Self::digit_char(0));
+        }
+        let digits = self
+            .digits
+            .iter()
+            .rev()
+            .map(|digit| Self::digit_char(*digit));
+        write!(f, "{}", digits.collect::<String>())
+    }
+}
+
+impl<S: NumeralSystem> Ord for Balanced<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the leading digit outweighs all digits after it, so it decides the sign and a
+        // longer number is further away from zero than a shorter one:
+        let sign = |number: &Balanced<S>| number.digits.last().map_or(0, |digit| digit.signum());
+        match self.digits.len().cmp(&other.digits.len()) {
+            Ordering::Less => 0.cmp(&sign(other)),
+            Ordering::Greater => sign(self).cmp(&0),
+            Ordering::Equal => self.digits.iter().rev().cmp(other.digits.iter().rev()),
+        }
+    }
+}
+
+impl<S: NumeralSystem> PartialOrd for Balanced<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: NumeralSystem> Add for Balanced<S> {
+    type Output = Balanced<S>;
+
+    fn add(self, other: Balanced<S>) -> Balanced<S> {
+        Balanced::add(&self, &other)
+    }
+}
+
+impl<S: NumeralSystem> Sub for Balanced<S> {
+    type Output = Balanced<S>;
+
+    fn sub(self, other: Balanced<S>) -> Balanced<S> {
+        Balanced::add(&self, &other.neg())
+    }
+}
+
+impl<S: NumeralSystem> Mul for Balanced<S> {
+    type Output = Balanced<S>;
+
+    fn mul(self, other: Balanced<S>) -> Balanced<S> {
+        Balanced::mul(&self, &other)
+    }
+}
+
+impl<S: NumeralSystem> Neg for Balanced<S> {
+    type Output = Balanced<S>;
+
+    fn neg(self) -> Balanced<S> {
+        Balanced::neg(&self)
+    }
+}
+
+impl<S: NumeralSystem> Sum for Balanced<S> {
+    fn sum<I: Iterator<Item = Balanced<S>>>(iter: I) -> Balanced<S> {
+        iter.fold(Balanced::zero(), |sum, number| Balanced::add(&sum, &number))
+    }
+}
+
+impl<'a, S: NumeralSystem> Sum<&'a Balanced<S>> for Balanced<S> {
+    fn sum<I: Iterator<Item = &'a Balanced<S>>>(iter: I) -> Balanced<S> {
+        iter.fold(Balanced::zero(), |sum, number| Balanced::add(&sum, number))
+    }
+}
+
+impl<S: NumeralSystem> From<i128> for Balanced<S> {
+    fn from(number: i128) -> Balanced<S> {
+        let mut digits = Vec::new();
+        let mut rest = number;
+        while rest != 0 {
+            let (digit, next) = Self::split_digit(rest);
+            digits.push(digit);
+            rest = next;
+        }
+        Balanced {
+            digits,
+            system: PhantomData,
+        }
+    }
+}
+
+impl<S: NumeralSystem> From<i64> for Balanced<S> {
+    fn from(number: i64) -> Balanced<S> {
+        Balanced::from(number as i128)
+    }
+}
+
+impl<S: NumeralSystem> TryFrom<&Balanced<S>> for i128 {
+    type Error = ArithmeticError;
+
+    fn try_from(number: &Balanced<S>) -> Result<i128, ArithmeticError> {
+        // `value * (BASE - 1) + (value + digit)` instead of `value * BASE + digit`, because
+        // the product alone may overflow just before the digit brings it back into range:
+        number.digits.iter().rev().try_fold(0i128, |value, digit| {
+            value
+                .checked_mul(S::BASE as i128 - 1)
+                .and_then(|product| product.checked_add(value + *digit as i128))
+                .ok_or(ArithmeticError::Overflow)
+        })
+    }
+}
+
+impl<S: NumeralSystem> TryFrom<&Balanced<S>> for i64 {
+    type Error = ArithmeticError;
+
+    fn try_from(number: &Balanced<S>) -> Result<i64, ArithmeticError> {
+        i64::try_from(i128::try_from(number)?).map_err(|_| ArithmeticError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_system<S: NumeralSystem>() {
+        assert_eq!(S::BASE % 2, 1);
+        assert_eq!(S::DIGITS.len(), S::BASE as usize);
+        assert!(S::DIGITS.is_ascii());
+        assert_eq!(Balanced::<S>::digit_value('0'), Some(0));
+    }
+
+    fn check_arithmetic<S: NumeralSystem>() {
+        let numbers = (-130..130).map(|number| number * 37).collect::<Vec<i64>>();
+        for left in numbers.iter().step_by(7) {
+            for right in &numbers {
+                let (a, b) = (Balanced::<S>::from(*left), Balanced::<S>::from(*right));
+                assert_eq!(a.clone() + b.clone(), Balanced::from(left + right));
+                assert_eq!(a.clone() - b.clone(), Balanced::from(left - right));
+                assert_eq!(a.clone() * b.clone(), Balanced::from(left * right));
+                assert_eq!(-b.clone(), Balanced::from(-right));
+                assert_eq!(a.cmp(&b), left.cmp(right));
+                assert_eq!(a.to_string().parse(), Ok(a));
+            }
+        }
+
+        // sums beyond i128 don't overflow:
+        let max = Balanced::<S>::from(i128::MAX);
+        let sum = [max.clone(), max.clone(), Balanced::from(2i64)]
+            .iter()
+            .sum::<Balanced<S>>();
+        assert_eq!(i128::try_from(&sum), Err(ArithmeticError::Overflow));
+        assert_eq!(sum - max.clone() - max.clone(), Balanced::from(2i64));
+        assert!(max.clone() * max.clone() > max);
+        assert_eq!(
+            Balanced::<S>::from(i128::MIN).to_string().parse(),
+            Ok(Balanced::<S>::from(i128::MIN))
+        );
+        assert_eq!(
+            i128::try_from(&Balanced::<S>::from(i128::MIN)),
+            Ok(i128::MIN)
+        );
+    }
+
+    #[test]
+    fn test_systems() {
+        check_system::<Ternary>();
+        check_system::<Quinary>();
+        check_system::<Nonary>();
+    }
+
+    #[test]
+    fn test_conversions() {
+        let examples = [
+            (0, "0"),
+            (1, "1"),
+            (8, "10T"),
+            (-5, "T11"),
+            (2022, "10T100T0"),
+        ];
+        for (decimal, text) in examples {
+            assert_eq!(BalancedTernary::from(decimal).to_string(), text);
+            assert_eq!(
+                i64::try_from(&text.parse::<BalancedTernary>().unwrap()),
+                Ok(decimal)
+            );
+        }
+        let examples = [(0, "0"), (40, "44"), (41, "1DD"), (-41, "A44")];
+        for (decimal, text) in examples {
+            assert_eq!(BalancedNonary::from(decimal).to_string(), text);
+            assert_eq!(
+                i64::try_from(&text.parse::<BalancedNonary>().unwrap()),
+                Ok(decimal)
+            );
+        }
+        assert_eq!(
+            "10=".parse::<BalancedTernary>(),
+            Err(AocError::parse("10=", "one of `T`, `0` or `1`").at_column(2))
+        );
+        assert!("".parse::<BalancedNonary>().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        check_arithmetic::<Ternary>();
+        check_arithmetic::<Quinary>();
+        check_arithmetic::<Nonary>();
+    }
+}
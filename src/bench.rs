@@ -0,0 +1,213 @@
+use crate::solution::{Part, Runner};
+use crate::{AocError, InputKind};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(Part::One) => "part1",
+            Stage::Solve(Part::Two) => "part2",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Statistics {
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Option<Statistics> {
+        let (min, max) = (*samples.iter().min()?, *samples.iter().max()?);
+        Some(Statistics {
+            mean: samples.iter().sum::<Duration>() / samples.len().try_into().ok()?,
+            min,
+            max,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DayTimings {
+    pub day: u8,
+    pub iterations: usize,
+    pub stages: Vec<(Stage, Statistics)>,
+}
+
+fn time<T, F>(f: F) -> Result<Duration, AocError>
+where
+    F: FnOnce() -> Result<T, AocError>,
+{
+    let start = Instant::now();
+    black_box(f()?);
+    Ok(start.elapsed())
+}
+
+/// Times parsing and each of the given parts separately.
+///
+/// Every iteration parses the input again so that the solve timings never benefit from
+/// work cached in a previous iteration.
+pub fn bench(
+    solution: &dyn Runner,
+    lines: &[String],
+    kind: InputKind,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<DayTimings, AocError> {
+    let mut samples = vec![Vec::with_capacity(iterations); parts.len() + 1];
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let input = solution.parse(lines, kind)?;
+        samples[0].push(start.elapsed());
+        for (part, part_samples) in parts.iter().zip(&mut samples[1..]) {
+            part_samples.push(time(|| solution.solve(input.as_ref(), *part))?);
+        }
+    }
+    let stages = [Stage::Parse]
+        .into_iter()
+        .chain(parts.iter().map(|part| Stage::Solve(*part)));
+    Ok(DayTimings {
+        day: solution.day(),
+        iterations,
+        stages: stages
+            .zip(&samples)
+            .filter_map(|(stage, samples)| Some((stage, Statistics::from_samples(samples)?)))
+            .collect(),
+    })
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3?}", duration)
+}
+
+pub fn format_table(timings: &[DayTimings]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}\n",
+        "day", "stage", "mean", "min", "max"
+    );
+    for day in timings {
+        for (stage, statistics) in &day.stages {
+            table.push_str(&format!(
+                "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}\n",
+                day.day,
+                stage.name(),
+                format_duration(statistics.mean),
+                format_duration(statistics.min),
+                format_duration(statistics.max)
+            ));
+        }
+    }
+    table
+}
+
+/// Renders the timings as a JSON array with one object per day and durations in
+/// nanoseconds, so that runs can be stored and compared.
+pub fn to_json(timings: &[DayTimings]) -> String {
+    let days = timings
+        .iter()
+        .map(|day| {
+            let stages = day
+                .stages
+                .iter()
+                .map(|(stage, statistics)| {
+                    format!(
+                        "\"{}\":{{\"mean_ns\":{},\"min_ns\":{},\"max_ns\":{}}}",
+                        stage.name(),
+                        statistics.mean.as_nanos(),
+                        statistics.min.as_nanos(),
+                        statistics.max.as_nanos()
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "{{\"day\":{},\"iterations\":{},\"stages\":{{{}}}}}",
+                day.day,
+                day.iterations,
+                stages.join(",")
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", days.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day6::Day6;
+
+    fn timings() -> Vec<DayTimings> {
+        let statistics = Statistics {
+            mean: Duration::from_micros(2),
+            min: Duration::from_micros(1),
+            max: Duration::from_micros(4),
+        };
+        vec![DayTimings {
+            day: 6,
+            iterations: 3,
+            stages: vec![
+                (Stage::Parse, statistics),
+                (Stage::Solve(Part::Two), statistics),
+            ],
+        }]
+    }
+
+    #[test]
+    fn test_statistics() {
+        let samples = [1, 5, 3].map(Duration::from_millis);
+        assert_eq!(
+            Statistics::from_samples(&samples),
+            Some(Statistics {
+                mean: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+                max: Duration::from_millis(5),
+            })
+        );
+        assert_eq!(Statistics::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let lines = vec!["mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string()];
+        let timings = bench(&Day6, &lines, InputKind::Example, &[Part::Two], 3).unwrap();
+        assert_eq!(timings.day, 6);
+        assert_eq!(
+            timings
+                .stages
+                .iter()
+                .map(|(stage, _)| *stage)
+                .collect::<Vec<_>>(),
+            vec![Stage::Parse, Stage::Solve(Part::Two)]
+        );
+        assert!(bench(&Day6, &[], InputKind::Real, &[Part::One], 3).is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&timings()),
+            concat!(
+                r#"[{"day":6,"iterations":3,"stages":{"#,
+                r#""parse":{"mean_ns":2000,"min_ns":1000,"max_ns":4000},"#,
+                r#""part2":{"mean_ns":2000,"min_ns":1000,"max_ns":4000}}}]"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_table() {
+        assert_eq!(
+            format_table(&timings()).lines().nth(2),
+            Some("  6  part2       2.000µs       1.000µs       4.000µs")
+        );
+    }
+}
@@ -0,0 +1,605 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
+use utilities::bench::{self, DayTimings};
+use utilities::days;
+use utilities::days::day11::{
+    conditions_lcm, export_trace, Day11, Monkey, Relief, TraceFormat, WorryType,
+};
+use utilities::days::day20::{decrypt, Day20, Decryption};
+use utilities::days::day21::{to_dot, Day21, DotOptions, WhatIf};
+use utilities::solution::{Part, Runner, Solution};
+use utilities::{AocError, InputKind, InputOptions, InputSource};
+
+const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [input options]
+       aoc bench <day|all> [--part <1|2>] [--iterations <n>] [--json] [input options]
+       aoc dot 21 [--values] [--path <from> <to>] [input options]
+       aoc repl 21 [input options]
+       aoc mix 20 [--part2] [--key <n>] [--rounds <n>] [--offsets <a,b,...>] [input options]
+       aoc trace 11 [--format <csv|counts|jsonl>] [--rounds <n>] [--relief <three|lcm|none>]
+                    [--worry <usize|u128>] [input options]
+
+dot prints the monkeys of day 21 as a Graphviz graph with an edge from every monkey to the
+monkeys it listens to. --values adds the number every monkey yells, --path highlights how
+<from> depends on <to>, e.g. `--path root humn`.
+
+repl reads commands from stdin to query the monkeys of day 21 and try out different jobs
+for them.
+
+mix decrypts the grove coordinates of day 20 and prints their sum. Without options this is
+part one, --part2 starts from the settings of part two (key 811589153, 10 rounds), and
+--key, --rounds and --offsets override single settings.
+
+trace records every inspection of day 11 and prints them as CSV (the default), the number
+of inspections per round and monkey as CSV (counts), or both as JSON lines (jsonl). It plays
+20 rounds, relieved by dividing by three like part one unless --relief says otherwise: lcm
+keeps worry levels below the lcm of the test conditions like part two, none doesn't reduce
+them at all. Worry levels are stored as usize unless --worry is u128. If one overflows,
+the trace stops there and the overflow is reported after everything recorded before it.";
+
+#[derive(Debug, PartialEq)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+/// The settings of `mix`, which override those of the chosen part no matter their order.
+#[derive(Debug, PartialEq, Default)]
+struct MixOptions {
+    part_two: bool,
+    key: Option<i128>,
+    rounds: Option<usize>,
+    offsets: Option<Vec<i64>>,
+}
+
+impl MixOptions {
+    fn decryption(&self) -> Decryption {
+        let part = match self.part_two {
+            true => Decryption::part_two(),
+            false => Decryption::part_one(),
+        };
+        Decryption {
+            key: self.key.unwrap_or(part.key),
+            rounds: self.rounds.unwrap_or(part.rounds),
+            offsets: self.offsets.clone().unwrap_or(part.offsets),
+        }
+    }
+}
+
+/// How `trace` relieves worry levels; the lcm depends on the monkeys, which are only read
+/// after the arguments are parsed.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TraceRelief {
+    DivideByThree,
+    Lcm,
+    Unreduced,
+}
+
+impl TraceRelief {
+    fn relief(&self, monkeys: &[Monkey]) -> Result<Relief, AocError> {
+        Ok(match self {
+            TraceRelief::DivideByThree => Relief::DivideByThree,
+            TraceRelief::Lcm => Relief::Modulo(conditions_lcm(monkeys)?),
+            TraceRelief::Unreduced => Relief::Unreduced,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct TraceOptions {
+    format: TraceFormat,
+    rounds: usize,
+    relief: TraceRelief,
+    worry_type: WorryType,
+}
+
+impl Default for TraceOptions {
+    fn default() -> Self {
+        TraceOptions {
+            format: TraceFormat::InspectionsCsv,
+            rounds: 20,
+            relief: TraceRelief::DivideByThree,
+            worry_type: WorryType::Usize,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run,
+    Bench { iterations: usize, json: bool },
+    Dot(DotOptions),
+    Repl,
+    Mix(MixOptions),
+    Trace(TraceOptions),
+}
+
+impl Command {
+    /// The only day that a command which isn't about the answers works for.
+    fn day(&self) -> Option<u8> {
+        match self {
+            Command::Run | Command::Bench { .. } => None,
+            Command::Dot(_) | Command::Repl => Some(Day21::DAY),
+            Command::Mix(_) => Some(Day20::DAY),
+            Command::Trace(_) => Some(Day11::DAY),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    command: Command,
+    selection: Selection,
+    parts: Vec<Part>,
+    input: InputOptions,
+}
+
+fn parse_part(input: &str) -> Result<Part, String> {
+    input
+        .parse::<u8>()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("Invalid part '{}', expected 1 or 2.", input))
+}
+
+fn parse_selection(input: &str) -> Result<Selection, String> {
+    if input == "all" {
+        return Ok(Selection::All);
+    }
+    input
+        .parse::<u8>()
+        .map(Selection::Day)
+        .map_err(|_| format!("Invalid day '{}', expected a number or 'all'.", input))
+}
+
+fn parse_value<T: FromStr>(
+    option: &str,
+    value: Option<&String>,
+    expected: &str,
+) -> Result<T, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("{} requires {}.", option, expected))
+}
+
+fn parse_offsets(input: &str) -> Result<Vec<i64>, String> {
+    input
+        .split(',')
+        .map(|offset| offset.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| format!("Invalid offsets '{}'.", input))
+}
+
+/// Parses the value of `option`, which has to be one of `choices`.
+fn parse_choice<T: Copy>(
+    option: &str,
+    value: Option<&String>,
+    choices: &[(&str, T)],
+) -> Result<T, String> {
+    let names = choices.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    value
+        .and_then(|value| choices.iter().find(|(name, _)| name == value))
+        .map(|(_, choice)| *choice)
+        .ok_or_else(|| format!("{} requires one of {}.", option, names.join(", ")))
+}
+
+fn parse_arguments(arguments: &[String]) -> Result<Options, String> {
+    let mut arguments = arguments.iter();
+    let name = arguments
+        .next()
+        .ok_or_else(|| "Missing command.".to_string())?;
+    let mut command = match name.as_str() {
+        "run" => Command::Run,
+        "bench" => Command::Bench {
+            iterations: DEFAULT_ITERATIONS,
+            json: false,
+        },
+        "dot" => Command::Dot(DotOptions::default()),
+        "repl" => Command::Repl,
+        "mix" => Command::Mix(MixOptions::default()),
+        "trace" => Command::Trace(TraceOptions::default()),
+        _ => return Err(format!("Unknown command '{}'.", name)),
+    };
+    let mut selection = None;
+    let mut parts = Part::all().to_vec();
+    let mut input = InputOptions::default();
+    while let Some(argument) = arguments.next() {
+        if input.parse_argument(argument, &mut arguments)? {
+            continue;
+        }
+        match (&mut command, argument.as_str()) {
+            (Command::Run | Command::Bench { .. }, "--part" | "-p") => {
+                let part = arguments
+                    .next()
+                    .ok_or_else(|| "--part requires a value.".to_string())?;
+                parts = vec![parse_part(part)?];
+            }
+            (Command::Bench { iterations, .. }, "--iterations" | "-n") => {
+                *iterations = arguments
+                    .next()
+                    .and_then(|count| count.parse::<usize>().ok())
+                    .filter(|count| *count > 0)
+                    .ok_or_else(|| "--iterations requires a positive number.".to_string())?;
+            }
+            (Command::Bench { json, .. }, "--json") => *json = true,
+            (Command::Dot(dot_options), "--values") => dot_options.values = true,
+            (Command::Dot(dot_options), "--path") => {
+                let (Some(from), Some(to)) = (arguments.next(), arguments.next()) else {
+                    return Err("--path requires two monkey names.".to_string());
+                };
+                dot_options.path = Some((from.clone(), to.clone()));
+            }
+            (Command::Mix(mix_options), "--part2") => mix_options.part_two = true,
+            (Command::Mix(mix_options), "--key") => {
+                mix_options.key = Some(parse_value(argument, arguments.next(), "an integer")?);
+            }
+            (Command::Mix(mix_options), "--rounds") => {
+                mix_options.rounds = Some(parse_value(argument, arguments.next(), "a count")?);
+            }
+            (Command::Mix(mix_options), "--offsets") => {
+                let offsets = arguments
+                    .next()
+                    .ok_or_else(|| "--offsets requires a list of offsets.".to_string())?;
+                mix_options.offsets = Some(parse_offsets(offsets)?);
+            }
+            (Command::Trace(trace_options), "--format") => {
+                let formats = [
+                    ("csv", TraceFormat::InspectionsCsv),
+                    ("counts", TraceFormat::RoundCountsCsv),
+                    ("jsonl", TraceFormat::JsonLines),
+                ];
+                trace_options.format = parse_choice(argument, arguments.next(), &formats)?;
+            }
+            (Command::Trace(trace_options), "--rounds") => {
+                trace_options.rounds = parse_value(argument, arguments.next(), "a count")?;
+            }
+            (Command::Trace(trace_options), "--relief") => {
+                let reliefs = [
+                    ("three", TraceRelief::DivideByThree),
+                    ("lcm", TraceRelief::Lcm),
+                    ("none", TraceRelief::Unreduced),
+                ];
+                trace_options.relief = parse_choice(argument, arguments.next(), &reliefs)?;
+            }
+            (Command::Trace(trace_options), "--worry") => {
+                let worry_types = [("usize", WorryType::Usize), ("u128", WorryType::U128)];
+                trace_options.worry_type = parse_choice(argument, arguments.next(), &worry_types)?;
+            }
+            _ if selection.is_none() => selection = Some(parse_selection(argument)?),
+            _ => return Err(format!("Unexpected argument '{}'.", argument)),
+        }
+    }
+    let selection = selection.ok_or_else(|| "Missing day.".to_string())?;
+    input.validate()?;
+    if input.input.is_some() && selection == Selection::All {
+        return Err("--input can only be used with a single day.".to_string());
+    }
+    if let Some(day) = command.day() {
+        if selection != Selection::Day(day) {
+            return Err(format!("{} is only available for day {}.", name, day));
+        }
+    }
+    if command == Command::Repl && input.input == Some(InputSource::Stdin) {
+        return Err("repl reads its commands from stdin, so --input can't be `-`.".to_string());
+    }
+    Ok(Options {
+        command,
+        selection,
+        parts,
+        input,
+    })
+}
+
+fn run(
+    solution: &dyn Runner,
+    source: &InputSource,
+    kind: InputKind,
+    parts: &[Part],
+) -> Result<(), AocError> {
+    let day = solution.day();
+    let lines = source.read(day)?;
+    let input = solution
+        .parse(&lines, kind)
+        .map_err(|err| err.in_file(source.name(day)))?;
+    for part in parts {
+        let answer = solution.solve(input.as_ref(), *part)?;
+        println!("Day {} part {}: {}", day, part.number(), answer);
+    }
+    Ok(())
+}
+
+fn bench(
+    solution: &dyn Runner,
+    source: &InputSource,
+    kind: InputKind,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<DayTimings, AocError> {
+    let day = solution.day();
+    let lines = source.read(day)?;
+    bench::bench(solution, &lines, kind, parts, iterations)
+        .map_err(|err| err.in_file(source.name(day)))
+}
+
+/// Runs or benchmarks the selected days, reporting the failure of single days without
+/// stopping.
+fn solve_days(options: &Options) -> ExitCode {
+    let solutions = match options.selection {
+        Selection::All => days::all(),
+        Selection::Day(day) => match days::get(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("Day {} is not solved yet.", day);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let source = options.input.source();
+    let kind = options.input.kind;
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut timings = Vec::new();
+    for solution in solutions {
+        let result = match options.command {
+            Command::Bench { iterations, .. } => {
+                bench(solution, &source, kind, &options.parts, iterations)
+                    .map(|day| timings.push(day))
+            }
+            _ => run(solution, &source, kind, &options.parts),
+        };
+        if let Err(msg) = result {
+            eprintln!("Day {} failed: {}", solution.day(), msg);
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    match options.command {
+        Command::Bench { json: true, .. } => println!("{}", bench::to_json(&timings)),
+        Command::Bench { json: false, .. } => print!("{}", bench::format_table(&timings)),
+        _ => (),
+    }
+    exit_code
+}
+
+fn read_day<S: Solution>(input: &InputOptions) -> Result<S::Input, AocError> {
+    let source = input.source();
+    let lines = source.read(S::DAY)?;
+    S::parse_kind(&lines, input.kind).map_err(|err| err.in_file(source.name(S::DAY)))
+}
+
+fn repl(mut what_if: WhatIf) {
+    println!("{}\nEnter `quit` or end the input to stop.", WhatIf::HELP);
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("> ");
+        // the prompt only matters interactively, so failing to show it is fine:
+        io::stdout().flush().ok();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match line.trim() {
+            "" => (),
+            "quit" | "exit" => break,
+            "help" => println!("{}", WhatIf::HELP),
+            command => match what_if.execute(command) {
+                Ok(output) => println!("{}", output),
+                Err(err) => println!("{}", err),
+            },
+        }
+    }
+}
+
+/// Runs one of the commands that work with a single day beyond its answers.
+fn run_tool(options: &Options) -> Result<(), AocError> {
+    match &options.command {
+        Command::Dot(dot_options) => {
+            let monkeys = read_day::<Day21>(&options.input)?;
+            print!("{}", to_dot(&monkeys, dot_options)?);
+        }
+        Command::Repl => repl(WhatIf::new(read_day::<Day21>(&options.input)?)),
+        Command::Mix(mix_options) => {
+            let numbers = read_day::<Day20>(&options.input)?;
+            println!("{}", decrypt(&numbers, &mix_options.decryption())?);
+        }
+        Command::Trace(trace_options) => {
+            let monkeys = read_day::<Day11>(&options.input)?;
+            let relief = trace_options.relief.relief(&monkeys)?;
+            let (trace, result) = export_trace(
+                &monkeys,
+                trace_options.rounds,
+                relief,
+                trace_options.worry_type,
+                trace_options.format,
+            );
+            // the inspections before an overflow help to find its cause:
+            print!("{}", trace);
+            result?;
+        }
+        Command::Run | Command::Bench { .. } => (),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let arguments = env::args().skip(1).collect::<Vec<_>>();
+    let options = match parse_arguments(&arguments) {
+        Ok(options) => options,
+        Err(msg) => {
+            eprintln!("{}\n{}\n\n{}", msg, USAGE, InputOptions::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    if options.command.day().is_none() {
+        return solve_days(&options);
+    }
+    match run_tool(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn arguments(input: &str) -> Vec<String> {
+        input.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_arguments() {
+        assert_eq!(
+            parse_arguments(&arguments("run 11 --part 2")),
+            Ok(Options {
+                command: Command::Run,
+                selection: Selection::Day(11),
+                parts: vec![Part::Two],
+                input: InputOptions::default(),
+            })
+        );
+        assert_eq!(
+            parse_arguments(&arguments("run all")),
+            Ok(Options {
+                command: Command::Run,
+                selection: Selection::All,
+                parts: vec![Part::One, Part::Two],
+                input: InputOptions::default(),
+            })
+        );
+        assert!(parse_arguments(&arguments("run")).is_err());
+        assert!(parse_arguments(&arguments("run 3 --part 3")).is_err());
+        assert!(parse_arguments(&arguments("walk 3")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_arguments() {
+        let options = parse_arguments(&arguments("bench all --json -n 3")).unwrap();
+        assert_eq!(
+            options.command,
+            Command::Bench {
+                iterations: 3,
+                json: true
+            }
+        );
+        let options = parse_arguments(&arguments("bench 20")).unwrap();
+        assert_eq!(
+            options.command,
+            Command::Bench {
+                iterations: DEFAULT_ITERATIONS,
+                json: false
+            }
+        );
+        assert!(parse_arguments(&arguments("bench 20 --iterations 0")).is_err());
+        assert!(parse_arguments(&arguments("run 20 --json")).is_err());
+    }
+
+    #[test]
+    fn test_parse_input_arguments() {
+        let options = parse_arguments(&arguments("run all --example --input-root data")).unwrap();
+        assert_eq!(
+            options.input.source(),
+            InputSource::Root {
+                root: PathBuf::from("data"),
+                kind: InputKind::Example
+            }
+        );
+        let options = parse_arguments(&arguments("run 5 --input -")).unwrap();
+        assert_eq!(options.input.source(), InputSource::Stdin);
+        assert_eq!(options.input.kind, InputKind::Real);
+        let options = parse_arguments(&arguments("run 15 --input small.txt --example")).unwrap();
+        assert_eq!(
+            options.input.source(),
+            InputSource::File(PathBuf::from("small.txt"))
+        );
+        assert_eq!(options.input.kind, InputKind::Example);
+        assert!(parse_arguments(&arguments("run all --input day5.txt")).is_err());
+        assert!(parse_arguments(&arguments("run 5 --input day5.txt --input-root data")).is_err());
+        assert!(parse_arguments(&arguments("run 5 --input-root")).is_err());
+    }
+
+    #[test]
+    fn test_parse_day21_arguments() {
+        let options = parse_arguments(&arguments("dot 21 --values --path root humn --example"));
+        assert_eq!(
+            options.map(|options| options.command),
+            Ok(Command::Dot(DotOptions {
+                values: true,
+                path: Some(("root".to_string(), "humn".to_string())),
+            }))
+        );
+        let options = parse_arguments(&arguments("dot 21 --input -")).unwrap();
+        assert_eq!(options.input.source(), InputSource::Stdin);
+        let options = parse_arguments(&arguments("repl 21 --example")).unwrap();
+        assert_eq!(options.command, Command::Repl);
+        assert!(parse_arguments(&arguments("dot 20")).is_err());
+        assert!(parse_arguments(&arguments("dot all")).is_err());
+        assert!(parse_arguments(&arguments("dot 21 --part 1")).is_err());
+        assert!(parse_arguments(&arguments("dot 21 --path root")).is_err());
+        assert!(parse_arguments(&arguments("repl 21 --input -")).is_err());
+        assert!(parse_arguments(&arguments("repl 21 --values")).is_err());
+        assert!(parse_arguments(&arguments("run 21 --values")).is_err());
+    }
+
+    #[test]
+    fn test_parse_mix_arguments() {
+        let options = parse_arguments(&arguments("mix 20")).unwrap();
+        assert_eq!(options.command, Command::Mix(MixOptions::default()));
+        assert_eq!(MixOptions::default().decryption(), Decryption::part_one());
+
+        let options = parse_arguments(&arguments(
+            "mix 20 --rounds 3 --part2 --offsets 1,-2 --key -100000000000000000000 --input -",
+        ))
+        .unwrap();
+        assert_eq!(options.input.source(), InputSource::Stdin);
+        let Command::Mix(mix_options) = options.command else {
+            panic!("Expected the mix command, got {:?}.", options.command)
+        };
+        assert_eq!(
+            mix_options.decryption(),
+            Decryption {
+                key: -100000000000000000000,
+                rounds: 3,
+                offsets: vec![1, -2],
+            }
+        );
+        assert!(parse_arguments(&arguments("mix 20 --key")).is_err());
+        assert!(parse_arguments(&arguments("mix 20 --rounds -1")).is_err());
+        assert!(parse_arguments(&arguments("mix 20 --offsets 1,,2")).is_err());
+        assert!(parse_arguments(&arguments("mix 20 --part3")).is_err());
+        assert!(parse_arguments(&arguments("mix 21")).is_err());
+        assert!(parse_arguments(&arguments("run 20 --key 3")).is_err());
+    }
+
+    #[test]
+    fn test_parse_trace_arguments() {
+        let options = parse_arguments(&arguments("trace 11 --input monkeys.txt")).unwrap();
+        assert_eq!(options.command, Command::Trace(TraceOptions::default()));
+        assert_eq!(
+            options.input.source(),
+            InputSource::File(PathBuf::from("monkeys.txt"))
+        );
+        let options = parse_arguments(&arguments(
+            "trace 11 --format jsonl --rounds 10000 --relief lcm --worry u128 --example",
+        ))
+        .unwrap();
+        assert_eq!(
+            options.command,
+            Command::Trace(TraceOptions {
+                format: TraceFormat::JsonLines,
+                rounds: 10000,
+                relief: TraceRelief::Lcm,
+                worry_type: WorryType::U128,
+            })
+        );
+        assert!(parse_arguments(&arguments("trace 11 --format xml")).is_err());
+        assert!(parse_arguments(&arguments("trace 11 --relief")).is_err());
+        assert!(parse_arguments(&arguments("trace 11 --worry u64")).is_err());
+        assert!(parse_arguments(&arguments("trace 12")).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+use std::ops::{Index, IndexMut};
+
+/// Refers to an element of a [`CircularList`] no matter where the element moves.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Handle(usize);
+
+/// Wraps a signed index into `0..length`.
+pub fn wrap_index(index: i64, length: usize) -> usize {
+    index.rem_euclid(length as i64) as usize
+}
+
+/// A list whose end wraps around to its start, so that every index and offset is valid.
+///
+/// The order is split into buckets of about `√n` elements, which makes looking up and
+/// moving an element take `O(√n)` instead of `O(n)` time.
+#[derive(Debug, Clone)]
+pub struct CircularList<T> {
+    values: Vec<T>,
+    buckets: Vec<Vec<usize>>,
+    /// The bucket that currently holds each element.
+    bucket_of: Vec<usize>,
+    bucket_size: usize,
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        CircularList::new()
+    }
+}
+
+impl<T> CircularList<T> {
+    pub fn new() -> CircularList<T> {
+        CircularList {
+            values: Vec::new(),
+            buckets: Vec::new(),
+            bucket_of: Vec::new(),
+            bucket_size: 1,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Adds `value` after the last element.
+    pub fn push(&mut self, value: T) -> Handle {
+        let handle = self.values.len();
+        self.values.push(value);
+        self.bucket_of.push(self.buckets.len().saturating_sub(1));
+        match self.buckets.last_mut() {
+            Some(last) => last.push(handle),
+            None => self.buckets.push(vec![handle]),
+        }
+        if self.buckets[self.bucket_of[handle]].len() > 2 * self.bucket_size {
+            self.rebalance(0);
+        }
+        Handle(handle)
+    }
+
+    /// Splits the order into buckets of equal size again, starting at index `start`.
+    fn rebalance(&mut self, start: usize) {
+        let mut order = self.buckets.concat();
+        order.rotate_left(start);
+        self.bucket_size = ((order.len() as f64).sqrt() as usize).max(1);
+        self.buckets = order
+            .chunks(self.bucket_size)
+            .map(<[usize]>::to_vec)
+            .collect();
+        for (bucket, handles) in self.buckets.iter().enumerate() {
+            for handle in handles {
+                self.bucket_of[*handle] = bucket;
+            }
+        }
+    }
+
+    /// The index of the element, counted from the first one.
+    pub fn position(&self, handle: Handle) -> usize {
+        self.locate_handle(handle).0
+    }
+
+    /// The index of the element and its index within its bucket.
+    fn locate_handle(&self, Handle(handle): Handle) -> (usize, usize) {
+        let bucket = self.bucket_of[handle];
+        let before = self.buckets[..bucket].iter().map(Vec::len).sum::<usize>();
+        let offset = self.buckets[bucket]
+            .iter()
+            .position(|other| *other == handle)
+            .expect("Every element is in its bucket.");
+        (before + offset, offset)
+    }
+
+    /// The bucket that holds `index` and the index within that bucket.
+    fn locate_index(&self, mut index: usize) -> (usize, usize) {
+        for (bucket, handles) in self.buckets.iter().enumerate() {
+            if index < handles.len() {
+                return (bucket, index);
+            }
+            index -= handles.len();
+        }
+        // only inserting right after the last element gets here:
+        let last = self.buckets.len() - 1;
+        (last, self.buckets[last].len())
+    }
+
+    /// The element at `index`, which wraps around in both directions.
+    ///
+    /// Panics if the list is empty.
+    pub fn handle_at(&self, index: i64) -> Handle {
+        let (bucket, offset) = self.locate_index(wrap_index(index, self.len()));
+        Handle(self.buckets[bucket][offset])
+    }
+
+    /// The element `offset` steps after `handle`, or before it for negative offsets.
+    pub fn relative(&self, handle: Handle, offset: i64) -> Handle {
+        // reducing the offset first keeps the sum from overflowing:
+        let index = self.position(handle) + wrap_index(offset, self.len());
+        self.handle_at((index % self.len()) as i64)
+    }
+
+    /// Takes the element out of the list and puts it back `offset` steps further along
+    /// the remaining elements, so moving by `len() - 1` steps leaves the order unchanged.
+    pub fn move_by(&mut self, handle: Handle, offset: i64) {
+        if self.len() < 2 {
+            return;
+        }
+        let (index, offset_in_bucket) = self.locate_handle(handle);
+        self.buckets[self.bucket_of[handle.0]].remove(offset_in_bucket);
+
+        let new_index = (index + wrap_index(offset, self.len() - 1)) % (self.len() - 1);
+        let (bucket, offset_in_bucket) = self.locate_index(new_index);
+        self.buckets[bucket].insert(offset_in_bucket, handle.0);
+        self.bucket_of[handle.0] = bucket;
+        if self.buckets[bucket].len() > 2 * self.bucket_size {
+            self.rebalance(0);
+        }
+    }
+
+    /// Turns the list so that the element at `index` comes first, which takes `O(n)` time.
+    pub fn rotate_left(&mut self, index: i64) {
+        if !self.is_empty() {
+            self.rebalance(wrap_index(index, self.len()));
+        }
+    }
+
+    /// The handles of all elements in order.
+    pub fn handles(&self) -> impl Iterator<Item = Handle> + '_ {
+        self.buckets.iter().flatten().map(|handle| Handle(*handle))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.handles().map(|handle| &self[handle])
+    }
+
+    /// All elements in order, starting at `handle` and wrapping around at the end.
+    pub fn iter_from(&self, handle: Handle) -> impl Iterator<Item = &T> {
+        let start = self.position(handle);
+        self.iter().skip(start).chain(self.iter().take(start))
+    }
+
+    /// The first element for which `predicate` holds.
+    pub fn find<F>(&self, mut predicate: F) -> Option<Handle>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.handles().find(|handle| predicate(&self[*handle]))
+    }
+}
+
+impl<T> Index<Handle> for CircularList<T> {
+    type Output = T;
+
+    fn index(&self, Handle(handle): Handle) -> &T {
+        &self.values[handle]
+    }
+}
+
+impl<T> IndexMut<Handle> for CircularList<T> {
+    fn index_mut(&mut self, Handle(handle): Handle) -> &mut T {
+        &mut self.values[handle]
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values = iter.into_iter().collect::<Vec<_>>();
+        let mut list = CircularList {
+            buckets: vec![(0..values.len()).collect()],
+            bucket_of: vec![0; values.len()],
+            values,
+            bucket_size: 1,
+        };
+        list.rebalance(0);
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contents(list: &CircularList<i64>) -> Vec<i64> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_wrap_index() {
+        let length: usize = 10;
+        for i in 0..length {
+            assert_eq!(wrap_index(i as i64, length), i);
+        }
+        assert_eq!(wrap_index(-20, length), 0);
+        assert_eq!(wrap_index(-10, length), 0);
+        assert_eq!(wrap_index(-1, length), length - 1);
+        assert_eq!(wrap_index(11, length), 1);
+        assert_eq!(wrap_index(21, length), 1);
+        assert_eq!(wrap_index(25, length), 5);
+    }
+
+    #[test]
+    fn test_lookups() {
+        let list = (0..10).collect::<CircularList<i64>>();
+        let three = list.find(|value| *value == 3).unwrap();
+        assert_eq!(list.position(three), 3);
+        assert_eq!(list[list.relative(three, 8)], 1);
+        assert_eq!(list[list.relative(three, -4)], 9);
+        assert_eq!(list[list.handle_at(-1)], 9);
+        assert_eq!(
+            list.iter_from(three).copied().collect::<Vec<_>>(),
+            vec![3, 4, 5, 6, 7, 8, 9, 0, 1, 2]
+        );
+        assert_eq!(list.find(|value| *value == 10), None);
+        // i64::MAX is 7 and i64::MIN is 2 modulo 10:
+        assert_eq!(list[list.relative(three, i64::MAX)], 0);
+        assert_eq!(list[list.relative(three, i64::MIN)], 5);
+    }
+
+    #[test]
+    fn test_move_by() {
+        let mut list = [1, 2, -3, 3, -2, 0, 4]
+            .into_iter()
+            .collect::<CircularList<i64>>();
+        let handles = list.handles().collect::<Vec<_>>();
+        list.move_by(handles[0], 1);
+        assert_eq!(contents(&list), vec![2, 1, -3, 3, -2, 0, 4]);
+        list.move_by(handles[2], -3);
+        assert_eq!(contents(&list), vec![2, 1, 3, -2, 0, -3, 4]);
+        // the moving element doesn't count, so a full turn needs one step less:
+        list.move_by(handles[1], 6);
+        assert_eq!(contents(&list), vec![2, 1, 3, -2, 0, -3, 4]);
+        assert_eq!(list[handles[6]], 4);
+
+        list[handles[6]] = 5;
+        list.rotate_left(-2);
+        assert_eq!(contents(&list), vec![-3, 5, 2, 1, 3, -2, 0]);
+        assert_eq!(list.position(handles[0]), 3);
+    }
+
+    #[test]
+    fn test_move_by_extreme_offsets() {
+        let list = (0..7).collect::<CircularList<i64>>();
+        let handle = list.handle_at(3);
+        // i64::MAX is 1 and i64::MIN is 4 modulo the 6 other elements:
+        for (offset, reduced) in [(i64::MAX, 1), (i64::MIN, 4), (i64::MIN + 1, 5)] {
+            let (mut moved, mut expected) = (list.clone(), list.clone());
+            moved.move_by(handle, offset);
+            expected.move_by(handle, reduced);
+            assert_eq!(contents(&moved), contents(&expected));
+        }
+    }
+
+    #[test]
+    fn test_push() {
+        let mut list = CircularList::new();
+        let handles = (0..50).map(|value| list.push(value)).collect::<Vec<_>>();
+        assert_eq!(contents(&list), (0..50).collect::<Vec<_>>());
+        assert_eq!(list.position(handles[42]), 42);
+        list.move_by(handles[0], -1);
+        assert_eq!(list.position(handles[0]), 48);
+        assert_eq!(list[list.handle_at(49)], 49);
+    }
+}
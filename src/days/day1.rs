@@ -0,0 +1,55 @@
+use crate::input::records;
+use crate::solution::Solution;
+use crate::AocError;
+
+pub struct Day1;
+
+fn calorie_sums(lines: &[String]) -> Result<Vec<u32>, AocError> {
+    let mut sums = records(lines)
+        .map(|record| {
+            record
+                .enumerate()
+                .map(|(index, line)| {
+                    line.parse::<u32>()
+                        .map_err(|_| AocError::parse(line, "a calorie count").at_line(index))
+                })
+                .sum::<Result<u32, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    sums.sort();
+    Ok(sums)
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        calorie_sums(lines)
+    }
+
+    fn part_one(sums: &Self::Input) -> Result<u32, AocError> {
+        sums.iter()
+            .max()
+            .copied()
+            .ok_or_else(|| AocError::solve("No elves found."))
+    }
+
+    fn part_two(sums: &Self::Input) -> Result<u32, AocError> {
+        Ok(sums.iter().rev().take(3).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calorie_sums_keeps_last_group() {
+        let lines = ["1000", "2000", "", "4000", "", "5000", "6000"].map(str::to_string);
+        assert_eq!(calorie_sums(&lines), Ok(vec![3000, 4000, 11000]));
+    }
+}
@@ -0,0 +1,343 @@
+use crate::grid::{Grid, Point};
+use crate::solution::Solution;
+use crate::AocError;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt;
+
+pub struct Day10;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Operator {
+    Noop = 1,
+    Addx = 2,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Instruction {
+    operator: Operator,
+    operand: Option<i32>,
+}
+
+fn parse_operator(input: &str) -> Option<Operator> {
+    match input {
+        "noop" => Some(Operator::Noop),
+        "addx" => Some(Operator::Addx),
+        _ => None,
+    }
+}
+
+fn parse_instruction<T>(input: T) -> Result<Instruction, AocError>
+where
+    T: Into<String>,
+{
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new(r"^(?P<operator>addx|noop)\s?(?P<operand>-?\d+)?$").unwrap();
+    };
+    let input_string = input.into();
+    let error = || AocError::parse(input_string.as_str(), "`noop` or `addx N`");
+    let captures = RE.captures(input_string.as_str()).ok_or_else(error)?;
+    let operator = captures
+        .name("operator")
+        .and_then(|operator| parse_operator(operator.as_str()))
+        .ok_or_else(error)?;
+    let operand = match captures.name("operand") {
+        Some(capture) => Some(capture.as_str().parse::<i32>().map_err(|_| {
+            AocError::parse(input_string.as_str(), "a number").at_column(capture.start())
+        })?),
+        None => None,
+    };
+    if (operator == Operator::Addx) != operand.is_some() {
+        return Err(error());
+    }
+    Ok(Instruction { operator, operand })
+}
+
+#[derive(Debug, Clone)]
+pub struct Screen {
+    pixels: Grid<char>,
+}
+
+impl Screen {
+    fn new() -> Screen {
+        Screen {
+            pixels: Grid::new(40, 6, '.'),
+        }
+    }
+
+    fn set_pixel(&mut self, x: i32, y: i32, crt_position: i32) {
+        self.pixels[Point::new(y as usize, crt_position as usize)] =
+            if is_pixel_active(crt_position, x) {
+                '#'
+            } else {
+                '.'
+            };
+    }
+}
+
+/// Starts with a newline so that the letters line up when printed after a label.
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n{}", self.pixels)
+    }
+}
+
+fn cycle_to_crt_line(cycle: &usize) -> i32 {
+    ((cycle.saturating_sub(1) / 40) % 6) as i32
+}
+
+fn cycle_to_crt_position(cycle: &usize) -> i32 {
+    (cycle.saturating_sub(1) % 40) as i32
+}
+
+fn is_pixel_active(crt_position: i32, sprite_position: i32) -> bool {
+    (sprite_position - crt_position).abs() < 2
+}
+
+#[derive(Debug, Clone)]
+struct DisplaySystem {
+    program: Vec<Instruction>,
+    register_x: i32,
+    screen: Screen,
+}
+
+impl DisplaySystem {
+    fn new(program: Vec<Instruction>) -> DisplaySystem {
+        DisplaySystem {
+            program,
+            register_x: 1,
+            screen: Screen::new(),
+        }
+    }
+    fn execute_cycles(
+        &mut self,
+        cycle_count: usize,
+        should_measure_signal_strength: fn(&usize) -> bool,
+    ) -> Result<Vec<i32>, AocError> {
+        if self.program.is_empty() {
+            return Err(AocError::solve("There are no instructions to execute."));
+        }
+        let mut result = Vec::new();
+        let mut instruction_iter = self.program.iter();
+        let mut current_instruction = None;
+        let mut cycles_to_next_instruction = 0;
+
+        for cycle in 1..=cycle_count {
+            if cycles_to_next_instruction == 0 {
+                let instruction = instruction_iter.next().ok_or_else(|| {
+                    AocError::solve(format!(
+                        "The program ends after {} cycles, but {} cycles are needed.",
+                        cycle - 1,
+                        cycle_count
+                    ))
+                })?;
+                cycles_to_next_instruction = instruction.operator.clone() as i32;
+                current_instruction = Some(instruction);
+            }
+            self.screen.set_pixel(
+                self.register_x,
+                cycle_to_crt_line(&cycle),
+                cycle_to_crt_position(&cycle),
+            );
+            if should_measure_signal_strength(&cycle) {
+                result.push(self.register_x * cycle as i32);
+            }
+            cycles_to_next_instruction -= 1;
+            if cycles_to_next_instruction == 0 {
+                if let Some(Instruction {
+                    operator: Operator::Addx,
+                    operand: Some(operand),
+                }) = current_instruction
+                {
+                    self.register_x += operand;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn parse_program(input: Vec<String>) -> Result<Vec<Instruction>, AocError> {
+    if input.is_empty() {
+        return Err(AocError::unexpected_end("at least one instruction"));
+    }
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse_instruction(line).map_err(|err| err.at_line(index)))
+        .collect()
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type PartOne = i32;
+    type PartTwo = Screen;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        parse_program(lines.to_vec())
+    }
+
+    fn part_one(program: &Self::Input) -> Result<i32, AocError> {
+        let mut display_system = DisplaySystem::new(program.clone());
+        Ok(display_system
+            .execute_cycles(220, |cycle| cycle % 40 == 20)?
+            .iter()
+            .sum())
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Screen, AocError> {
+        let mut display_system = DisplaySystem::new(program.clone());
+        display_system.execute_cycles(
+            program.iter().map(|i| i.operator.clone() as usize).sum(),
+            |_| false,
+        )?;
+        Ok(display_system.screen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    #[test]
+    fn test_parse_instruction() {
+        assert!(parse_instruction("").is_err());
+        assert!(parse_instruction("addx").is_err());
+        assert!(parse_instruction("noop 3").is_err());
+        assert_eq!(
+            parse_instruction("noop"),
+            Ok(Instruction {
+                operator: Operator::Noop,
+                operand: None
+            })
+        );
+        assert_eq!(
+            parse_instruction("addx 3"),
+            Ok(Instruction {
+                operator: Operator::Addx,
+                operand: Some(3)
+            })
+        );
+        assert_eq!(
+            parse_instruction("addx -5"),
+            Ok(Instruction {
+                operator: Operator::Addx,
+                operand: Some(-5)
+            })
+        );
+    }
+
+    #[test]
+    #[allow(clippy::identity_op)]
+    fn test_display_system() {
+        let program = vec![
+            parse_instruction("noop").unwrap(),
+            parse_instruction("addx 3").unwrap(),
+            parse_instruction("addx -5").unwrap(),
+        ];
+
+        let mut display_system = DisplaySystem::new(program);
+
+        let signal_strengths = display_system.execute_cycles(5, |_cycle| true).unwrap();
+
+        assert_eq!(signal_strengths[0], 1 * 1);
+        assert_eq!(signal_strengths[1], 2 * 1);
+        assert_eq!(signal_strengths[2], 3 * 1);
+        assert_eq!(signal_strengths[3], 4 * 4);
+        assert_eq!(signal_strengths[4], 5 * 4);
+    }
+
+    #[test]
+    fn test_display_system_errors() {
+        let mut display_system = DisplaySystem::new(Vec::new());
+        assert_eq!(
+            display_system.execute_cycles(1, |_| false),
+            Err(AocError::solve("There are no instructions to execute."))
+        );
+
+        let program = vec![parse_instruction("addx 3").unwrap()];
+        let mut display_system = DisplaySystem::new(program);
+        assert_eq!(
+            display_system.execute_cycles(3, |_| false),
+            Err(AocError::solve(
+                "The program ends after 2 cycles, but 3 cycles are needed."
+            ))
+        );
+    }
+
+    #[test]
+    fn test_display_system_huge_example() {
+        let lines = read_input("inputs/day10-example.txt");
+        assert!(lines.is_ok());
+        let program = parse_program(lines.unwrap()).unwrap();
+
+        let mut display_system = DisplaySystem::new(program);
+
+        let signal_strengths = display_system
+            .execute_cycles(220, |cycle| cycle % 40 == 20)
+            .unwrap();
+
+        assert_eq!(signal_strengths[0], 420);
+        assert_eq!(signal_strengths[1], 1140);
+        assert_eq!(signal_strengths[2], 1800);
+        assert_eq!(signal_strengths[3], 2940);
+        assert_eq!(signal_strengths[4], 2880);
+        assert_eq!(signal_strengths[5], 3960);
+    }
+
+    #[test]
+    fn test_cycle_to_crt_position() {
+        assert_eq!(cycle_to_crt_position(&1), 0);
+        assert_eq!(cycle_to_crt_position(&6), 5);
+        assert_eq!(cycle_to_crt_position(&40), 39);
+        assert_eq!(cycle_to_crt_position(&41), 0);
+        assert_eq!(cycle_to_crt_position(&80), 39);
+        assert_eq!(cycle_to_crt_position(&201), 0);
+        assert_eq!(cycle_to_crt_position(&240), 39);
+    }
+
+    #[test]
+    fn test_is_pixel_active() {
+        assert!(!is_pixel_active(1, -1));
+        assert!(is_pixel_active(1, 0));
+        assert!(is_pixel_active(1, 1));
+        assert!(is_pixel_active(1, 2));
+        assert!(!is_pixel_active(1, 3));
+
+        assert!(!is_pixel_active(40, 38));
+        assert!(is_pixel_active(40, 39));
+        assert!(is_pixel_active(40, 40));
+        assert!(is_pixel_active(40, 41));
+        assert!(!is_pixel_active(40, 42));
+    }
+
+    #[test]
+    fn test_cycle_to_crt_line() {
+        assert_eq!(cycle_to_crt_line(&1), 0);
+        assert_eq!(cycle_to_crt_line(&40), 0);
+        assert_eq!(cycle_to_crt_line(&41), 1);
+        assert_eq!(cycle_to_crt_line(&80), 1);
+        assert_eq!(cycle_to_crt_line(&201), 5);
+        assert_eq!(cycle_to_crt_line(&240), 5);
+        assert_eq!(cycle_to_crt_line(&241), 0);
+    }
+
+    #[test]
+    fn test_screen() {
+        let lines = read_input("inputs/day10-example.txt");
+        assert!(lines.is_ok());
+        let program = parse_program(lines.unwrap()).unwrap();
+
+        let mut display_system = DisplaySystem::new(program);
+        display_system.execute_cycles(40, |_| true).unwrap();
+
+        assert_eq!(
+            display_system.screen.pixels.row(0).collect::<String>(),
+            "##..##..##..##..##..##..##..##..##..##.."
+        );
+    }
+}
@@ -0,0 +1,1357 @@
+use crate::input::{records, Record};
+use crate::number_theory::{lcm, ArithmeticError, Integer};
+use crate::solution::Solution;
+use crate::AocError;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+pub struct Day11;
+
+const STARTING_ITEMS: &str = "`Starting items: N, N, ...`";
+const OPERATION: &str = "`Operation: new = <expression>`";
+const TEST_CONDITION: &str = "`Test: divisible by N`";
+const TEST_BRANCH: &str = "`If <true|false>: throw to monkey N`";
+
+fn parse_starting_items(input: &str) -> Result<VecDeque<usize>, AocError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"\s+Starting items:\s(?P<items>(?:\d+,?\s?)+)").unwrap();
+    };
+    let error = || AocError::parse(input, STARTING_ITEMS);
+    let mut result = VecDeque::new();
+    let captures = RE.captures(input).ok_or_else(error)?;
+    let items_strings = captures
+        .name("items")
+        .ok_or_else(error)?
+        .as_str()
+        .split(',')
+        .map(|f| f.trim());
+    for item in items_strings {
+        result.push_back(item.parse::<usize>().map_err(|_| error())?);
+    }
+    Ok(result)
+}
+
+/// The right hand side of a monkey's operation, e.g. `old * (old - 3) + 2`.
+#[derive(Debug, PartialEq, Clone)]
+enum Expression {
+    Old,
+    Number(usize),
+    Add(Box<Expression>, Box<Expression>),
+    Subtract(Box<Expression>, Box<Expression>),
+    Multiply(Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    /// Evaluates the expression for the given worry level, or returns `None` if the result
+    /// would overflow or drop below zero.
+    fn evaluate<W: Worry>(&self, old: W) -> Option<W> {
+        match self {
+            Expression::Old => Some(old),
+            Expression::Number(number) => W::try_from(*number).ok(),
+            Expression::Add(left, right) => left.evaluate(old)?.checked_add(right.evaluate(old)?),
+            Expression::Subtract(left, right) => {
+                left.evaluate(old)?.checked_sub(right.evaluate(old)?)
+            }
+            Expression::Multiply(left, right) => {
+                left.evaluate(old)?.checked_mul(right.evaluate(old)?)
+            }
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression::Old | Expression::Number(_) => 3,
+            Expression::Multiply(_, _) => 2,
+            Expression::Add(_, _) | Expression::Subtract(_, _) => 1,
+        }
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (left, operator, right) = match self {
+            Expression::Old => return write!(f, "old"),
+            Expression::Number(number) => return write!(f, "{}", number),
+            Expression::Add(left, right) => (left, '+', right),
+            Expression::Subtract(left, right) => (left, '-', right),
+            Expression::Multiply(left, right) => (left, '*', right),
+        };
+        // operators are left associative, so only the right operand needs parentheses if it
+        // binds as strongly as this operator:
+        if left.precedence() < self.precedence() {
+            write!(f, "({})", left)?;
+        } else {
+            write!(f, "{}", left)?;
+        }
+        write!(f, " {} ", operator)?;
+        if right.precedence() <= self.precedence() {
+            write!(f, "({})", right)
+        } else {
+            write!(f, "{}", right)
+        }
+    }
+}
+
+/// A recursive descent parser for expressions over `old`, integer literals, `+`, `-`, `*`
+/// and parentheses.
+struct ExpressionParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> ExpressionParser<'a> {
+    fn parse(input: &'a str, offset: usize) -> Result<Expression, AocError> {
+        let mut parser = ExpressionParser {
+            input,
+            position: offset,
+        };
+        let expression = parser.sum()?;
+        parser.skip_whitespace();
+        match parser.position < input.len() {
+            true => Err(parser.error("an operator")),
+            false => Ok(expression),
+        }
+    }
+
+    fn error(&self, expected: &str) -> AocError {
+        AocError::parse(self.input, expected).at_column(self.position)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.position..].chars().next()
+    }
+
+    fn sum(&mut self) -> Result<Expression, AocError> {
+        let mut expression = self.product()?;
+        while let Some(operator @ ('+' | '-')) = self.peek() {
+            self.position += 1;
+            let right = Box::new(self.product()?);
+            expression = match operator {
+                '+' => Expression::Add(Box::new(expression), right),
+                _ => Expression::Subtract(Box::new(expression), right),
+            };
+        }
+        Ok(expression)
+    }
+
+    fn product(&mut self) -> Result<Expression, AocError> {
+        let mut expression = self.factor()?;
+        while let Some('*') = self.peek() {
+            self.position += 1;
+            expression = Expression::Multiply(Box::new(expression), Box::new(self.factor()?));
+        }
+        Ok(expression)
+    }
+
+    fn factor(&mut self) -> Result<Expression, AocError> {
+        let expected = "`old`, a number or `(`";
+        match self.peek() {
+            Some('(') => {
+                self.position += 1;
+                let expression = self.sum()?;
+                match self.peek() {
+                    Some(')') => {
+                        self.position += 1;
+                        Ok(expression)
+                    }
+                    _ => Err(self.error("`)`")),
+                }
+            }
+            Some('0'..='9') => {
+                let rest = &self.input[self.position..];
+                let digits = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let number = rest[..digits]
+                    .parse::<usize>()
+                    .map_err(|_| self.error("a number that fits into a worry level"))?;
+                self.position += digits;
+                Ok(Expression::Number(number))
+            }
+            _ if self.input[self.position..].starts_with("old") => {
+                self.position += 3;
+                Ok(Expression::Old)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+}
+
+fn parse_operation(input: &str) -> Result<Expression, AocError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^\s*Operation:\s*new\s*=(?P<expression>.*)$").unwrap();
+    };
+    let captures = RE
+        .captures(input)
+        .ok_or_else(|| AocError::parse(input, OPERATION))?;
+    let expression = captures
+        .name("expression")
+        .ok_or_else(|| AocError::parse(input, OPERATION))?;
+    ExpressionParser::parse(input, expression.start())
+}
+
+fn parse_test_condition(input: &str) -> Result<i32, AocError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"\s+Test:\sdivisible\sby\s(?P<divisor>\d+)").unwrap();
+    };
+    RE.captures(input)
+        .and_then(|captures| captures.name("divisor")?.as_str().parse::<i32>().ok())
+        .filter(|divisor| *divisor > 0)
+        .ok_or_else(|| AocError::parse(input, TEST_CONDITION))
+}
+
+fn parse_test_branch(input: &str) -> Result<(bool, i32), AocError> {
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new(r"\s+If\s(?P<result>true|false):\sthrow to monkey\s(?P<monkey>\d+)")
+                .unwrap();
+    };
+    let error = || AocError::parse(input, TEST_BRANCH);
+    let captures = RE.captures(input).ok_or_else(error)?;
+    let when = captures
+        .name("result")
+        .and_then(|result| result.as_str().parse::<bool>().ok())
+        .ok_or_else(error)?;
+    let monkey = captures
+        .name("monkey")
+        .and_then(|monkey| monkey.as_str().parse::<i32>().ok())
+        .ok_or_else(error)?;
+    Ok((when, monkey))
+}
+
+/// Unsigned integer types that can hold the worry level of an item.
+pub trait Worry: Integer + fmt::Display + TryFrom<usize> {}
+
+impl<T: Integer + fmt::Display + TryFrom<usize>> Worry for T {}
+
+/// The integer type worry levels are stored in while the monkeys play.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum WorryType {
+    Usize,
+    /// Gives an unreduced simulation a few more rounds before the worry levels overflow.
+    U128,
+}
+
+/// How an item's worry level changes after a monkey is done inspecting it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Relief {
+    DivideByThree,
+    /// Keeps worry levels below the modulus, which doesn't change any test result if the
+    /// modulus is a multiple of every test condition.
+    Modulo(usize),
+    Unreduced,
+}
+
+impl Relief {
+    fn apply<W: Worry>(&self, worry: W) -> Option<W> {
+        match self {
+            Relief::DivideByThree => worry.checked_div(W::try_from(3).ok()?),
+            Relief::Modulo(modulus) => worry.checked_rem(W::try_from(*modulus).ok()?),
+            Relief::Unreduced => Some(worry),
+        }
+    }
+}
+
+/// An item together with its position in the input, so it can be followed between monkeys.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Item<W> {
+    id: usize,
+    worry: W,
+}
+
+/// What happened to an item during a monkey's turn.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Throw<W> {
+    item: Item<W>,
+    worry_before: W,
+    test_result: bool,
+    target: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Monkey<W = usize> {
+    id: usize,
+    items: VecDeque<Item<W>>,
+    operation: Expression,
+    test_condition: i32,
+    test_branches: [(bool, i32); 2],
+    inspection_count: usize,
+}
+
+impl Monkey {
+    /// The same monkey with its items' worry levels stored as `W`.
+    fn with_worry<W: Worry>(&self) -> Result<Monkey<W>, AocError> {
+        let items = self
+            .items
+            .iter()
+            .map(|item| {
+                let worry = W::try_from(item.worry).map_err(|_| {
+                    AocError::solve(format!("The worry level {} is out of range.", item.worry))
+                })?;
+                Ok(Item { id: item.id, worry })
+            })
+            .collect::<Result<_, AocError>>()?;
+        Ok(Monkey {
+            id: self.id,
+            items,
+            operation: self.operation.clone(),
+            test_condition: self.test_condition,
+            test_branches: self.test_branches,
+            inspection_count: self.inspection_count,
+        })
+    }
+}
+
+impl<W: Worry> Monkey<W> {
+    /// Applies the operation and the relief to the worry level `old` of the item with the
+    /// given id, if it is known.
+    fn inspect_item(&self, item: Option<usize>, old: W, relief: Relief) -> Result<W, AocError> {
+        self.operation
+            .evaluate(old)
+            .and_then(|worry| relief.apply(worry))
+            .ok_or_else(|| {
+                let item = item.map_or("an item".to_string(), |id| format!("item {}", id));
+                AocError::solve(format!(
+                    "Monkey {} overflows inspecting {}: applying `new = {}` to old = {} \
+                     gives a worry level out of range.",
+                    self.id, item, self.operation, old
+                ))
+            })
+    }
+
+    fn test_item(&self, item: W) -> bool {
+        W::try_from(self.test_condition as usize)
+            .ok()
+            .and_then(|condition| item.checked_rem(condition))
+            == Some(W::ZERO)
+    }
+
+    fn throw_target(&self, test_result: bool) -> i32 {
+        if self.test_branches[0].0 == test_result {
+            self.test_branches[0].1
+        } else {
+            self.test_branches[1].1
+        }
+    }
+
+    /// Throws the items one after the other. An item that can't be inspected stays with the
+    /// monkey, while the ones thrown before it are in `thrown_items`.
+    fn play_turn(
+        &mut self,
+        relief: Relief,
+        thrown_items: &mut VecDeque<Throw<W>>,
+    ) -> Result<(), AocError> {
+        while let Some(item) = self.items.front().copied() {
+            let worry = self.inspect_item(Some(item.id), item.worry, relief)?;
+            self.items.pop_front();
+            let test_result = self.test_item(worry);
+            thrown_items.push_back(Throw {
+                item: Item { id: item.id, worry },
+                worry_before: item.worry,
+                test_result,
+                target: self.throw_target(test_result) as usize,
+            });
+            self.inspection_count += 1;
+        }
+        Ok(())
+    }
+}
+
+fn parse_next_line<'a, I, T, F>(input: &mut I, expected: &str, parse: F) -> Result<T, AocError>
+where
+    I: Iterator<Item = (usize, &'a String)>,
+    F: Fn(&str) -> Result<T, AocError>,
+{
+    let (index, line) = input
+        .next()
+        .ok_or_else(|| AocError::unexpected_end(expected))?;
+    parse(line).map_err(|err| err.at_line(index))
+}
+
+fn parse_monkey(record: Record) -> Result<Monkey, AocError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"Monkey\s(?P<monkey>\d+):").unwrap();
+    };
+    let header = "`Monkey N:`";
+    let input = &mut record.enumerate();
+    let id = parse_next_line(input, header, |line| {
+        RE.captures(line)
+            .and_then(|captures| captures.name("monkey")?.as_str().parse::<usize>().ok())
+            .ok_or_else(|| AocError::parse(line, header))
+    })?;
+    let starting_items = parse_next_line(input, STARTING_ITEMS, parse_starting_items)?;
+    let operation = parse_next_line(input, OPERATION, parse_operation)?;
+    let test_condition = parse_next_line(input, TEST_CONDITION, parse_test_condition)?;
+    let mut test_branches = [(false, 0); 2];
+    for branch in 0..test_branches.len() {
+        test_branches[branch] = parse_next_line(input, TEST_BRANCH, |line| {
+            let (when, target) = parse_test_branch(line)?;
+            if branch > 0 && when == test_branches[0].0 {
+                // a monkey needs a target for either test result:
+                return Err(AocError::parse(line, format!("`If {}:`", !when)));
+            }
+            match target as usize == id {
+                true => Err(AocError::parse(line, format!("a monkey other than {}", id))),
+                false => Ok((when, target)),
+            }
+        })?;
+    }
+    if let Some((index, line)) = input.next() {
+        return Err(AocError::parse(line, "an empty line").at_line(index));
+    }
+    Ok(Monkey {
+        id,
+        // the ids are assigned once all monkeys are parsed:
+        items: starting_items
+            .into_iter()
+            .map(|worry| Item { id: 0, worry })
+            .collect(),
+        operation,
+        test_condition,
+        test_branches,
+        inspection_count: 0,
+    })
+}
+
+/// Parses all monkeys and orders them by their ids, which have to be numbered from zero
+/// without gaps, so that every throw target is a valid index.
+fn parse_monkeys(input: &[String]) -> Result<Vec<Monkey>, AocError> {
+    let records = records(input).collect_vec();
+    let mut monkeys = records
+        .iter()
+        .map(|record| parse_monkey(*record))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut ids = HashSet::new();
+    for (monkey, record) in monkeys.iter().zip(&records) {
+        if !ids.insert(monkey.id) {
+            let expected = format!(
+                "a monkey id other than {}, which is already taken",
+                monkey.id
+            );
+            return Err(
+                AocError::parse(&record.lines[0], expected).at_line(record.first_line_index)
+            );
+        }
+    }
+    if let Some(missing) = (0..monkeys.len()).find(|id| !ids.contains(id)) {
+        // as the ids are unique, a gap leaves at least one of them out of range:
+        let (_, record) = monkeys
+            .iter()
+            .zip(&records)
+            .find(|(monkey, _)| monkey.id >= monkeys.len())
+            .expect("A missing id should push another one out of range.");
+        let expected = format!(
+            "a monkey id below {}, since monkey {} is missing",
+            monkeys.len(),
+            missing
+        );
+        return Err(AocError::parse(&record.lines[0], expected).at_line(record.first_line_index));
+    }
+    for (monkey, record) in monkeys.iter().zip(&records) {
+        // the branches are the last two lines of a record:
+        let branch_lines = record.enumerate().skip(4);
+        for ((_, target), (index, line)) in monkey.test_branches.iter().zip(branch_lines) {
+            if *target as usize >= monkeys.len() {
+                let expected = format!("a monkey between 0 and {}", monkeys.len() - 1);
+                return Err(AocError::parse(line, expected).at_line(index));
+            }
+        }
+    }
+
+    let items = monkeys
+        .iter_mut()
+        .flat_map(|monkey| monkey.items.iter_mut());
+    for (id, item) in items.enumerate() {
+        item.id = id;
+    }
+    monkeys.sort_by_key(|monkey| monkey.id);
+    Ok(monkeys)
+}
+
+fn catch_items<W>(monkeys: &mut [Monkey<W>], thrown_items: VecDeque<Throw<W>>) {
+    for throw in thrown_items {
+        monkeys[throw.target].items.push_back(throw.item)
+    }
+}
+
+/// Plays a round and calls `on_throw` with the index of the throwing monkey for every item,
+/// including the items thrown before a monkey fails to inspect one.
+fn play_round<W, F>(
+    monkeys: &mut [Monkey<W>],
+    relief: Relief,
+    mut on_throw: F,
+) -> Result<(), AocError>
+where
+    W: Worry,
+    F: FnMut(usize, &Throw<W>),
+{
+    for monkey_index in 0..monkeys.len() {
+        let mut turn_result = VecDeque::new();
+        let played = monkeys[monkey_index].play_turn(relief, &mut turn_result);
+        for throw in &turn_result {
+            on_throw(monkey_index, throw);
+        }
+        catch_items(monkeys, turn_result);
+        played?;
+    }
+    Ok(())
+}
+
+fn play_rounds<W: Worry>(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+) -> Result<Vec<usize>, AocError> {
+    let mut monkeys = monkeys
+        .iter()
+        .map(Monkey::with_worry::<W>)
+        .collect::<Result<Vec<_>, _>>()?;
+    for _round in 1..=rounds {
+        play_round(&mut monkeys, relief, |_, _| {})?;
+    }
+    Ok(monkeys
+        .iter()
+        .map(|monkey| monkey.inspection_count)
+        .collect())
+}
+
+/// Plays the given number of rounds and returns how many items each monkey inspected.
+///
+/// Fails as soon as a worry level doesn't fit into `worry_type` anymore.
+pub fn inspection_counts(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+    worry_type: WorryType,
+) -> Result<Vec<usize>, AocError> {
+    match worry_type {
+        WorryType::Usize => play_rounds::<usize>(monkeys, rounds, relief),
+        WorryType::U128 => play_rounds::<u128>(monkeys, rounds, relief),
+    }
+}
+
+/// A single inspection recorded by [`trace`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Inspection<W> {
+    pub round: usize,
+    pub monkey: usize,
+    pub item: usize,
+    pub worry_before: W,
+    pub worry_after: W,
+    pub test_result: bool,
+    pub target: usize,
+}
+
+/// Everything that happened while the monkeys played, in order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Trace<W> {
+    pub inspections: Vec<Inspection<W>>,
+    /// The number of inspections per monkey during each round.
+    pub round_counts: Vec<Vec<usize>>,
+}
+
+impl<W: fmt::Display> Trace<W> {
+    pub fn inspections_csv(&self) -> String {
+        let mut csv =
+            String::from("round,monkey,item,worry_before,worry_after,test_result,target\n");
+        for inspection in &self.inspections {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                inspection.round,
+                inspection.monkey,
+                inspection.item,
+                inspection.worry_before,
+                inspection.worry_after,
+                inspection.test_result,
+                inspection.target
+            ));
+        }
+        csv
+    }
+
+    /// One line per round and monkey, which is easier to plot than one column per monkey.
+    pub fn round_counts_csv(&self) -> String {
+        let mut csv = String::from("round,monkey,inspections\n");
+        for (round, counts) in self.round_counts.iter().enumerate() {
+            for (monkey, count) in counts.iter().enumerate() {
+                csv.push_str(&format!("{},{},{}\n", round + 1, monkey, count));
+            }
+        }
+        csv
+    }
+
+    /// Renders the inspections and round counts as JSON lines, each round's inspections
+    /// followed by its counts.
+    pub fn to_json_lines(&self) -> String {
+        let mut lines = String::new();
+        let mut inspections = self.inspections.iter().peekable();
+        for (round, counts) in self.round_counts.iter().enumerate() {
+            while let Some(inspection) =
+                inspections.next_if(|inspection| inspection.round == round + 1)
+            {
+                lines.push_str(&format!(
+                    "{{\"type\":\"inspection\",\"round\":{},\"monkey\":{},\"item\":{},\"worry_before\":{},\"worry_after\":{},\"test_result\":{},\"target\":{}}}\n",
+                    inspection.round,
+                    inspection.monkey,
+                    inspection.item,
+                    inspection.worry_before,
+                    inspection.worry_after,
+                    inspection.test_result,
+                    inspection.target
+                ));
+            }
+            lines.push_str(&format!(
+                "{{\"type\":\"round\",\"round\":{},\"inspections\":[{}]}}\n",
+                round + 1,
+                counts.iter().join(",")
+            ));
+        }
+        lines
+    }
+}
+
+/// Plays like [`inspection_counts`] but records every inspection on the way.
+///
+/// If a worry level overflows, the trace ends with the inspections and the partial round
+/// before the failing one, together with the error.
+pub fn trace<W: Worry>(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+) -> (Trace<W>, Result<(), AocError>) {
+    let mut trace = Trace {
+        inspections: Vec::new(),
+        round_counts: Vec::new(),
+    };
+    let monkeys = monkeys
+        .iter()
+        .map(Monkey::with_worry::<W>)
+        .collect::<Result<Vec<_>, _>>();
+    let mut monkeys = match monkeys {
+        Ok(monkeys) => monkeys,
+        Err(err) => return (trace, Err(err)),
+    };
+    for round in 1..=rounds {
+        let mut counts = vec![0; monkeys.len()];
+        let played = play_round(&mut monkeys, relief, |monkey, throw| {
+            counts[monkey] += 1;
+            trace.inspections.push(Inspection {
+                round,
+                monkey,
+                item: throw.item.id,
+                worry_before: throw.worry_before,
+                worry_after: throw.item.worry,
+                test_result: throw.test_result,
+                target: throw.target,
+            });
+        });
+        trace.round_counts.push(counts);
+        if played.is_err() {
+            return (trace, played);
+        }
+    }
+    (trace, Ok(()))
+}
+
+/// How [`export_trace`] renders a trace.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TraceFormat {
+    /// [`Trace::inspections_csv`]
+    InspectionsCsv,
+    /// [`Trace::round_counts_csv`]
+    RoundCountsCsv,
+    /// [`Trace::to_json_lines`]
+    JsonLines,
+}
+
+/// Traces the given number of rounds with worry levels of `worry_type` and renders the
+/// trace in `format`, which covers everything up to an overflow like [`trace`].
+pub fn export_trace(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+    worry_type: WorryType,
+    format: TraceFormat,
+) -> (String, Result<(), AocError>) {
+    fn render<W: fmt::Display>(
+        (trace, result): (Trace<W>, Result<(), AocError>),
+        format: TraceFormat,
+    ) -> (String, Result<(), AocError>) {
+        let text = match format {
+            TraceFormat::InspectionsCsv => trace.inspections_csv(),
+            TraceFormat::RoundCountsCsv => trace.round_counts_csv(),
+            TraceFormat::JsonLines => trace.to_json_lines(),
+        };
+        (text, result)
+    }
+    match worry_type {
+        WorryType::Usize => render(trace::<usize>(monkeys, rounds, relief), format),
+        WorryType::U128 => render(trace::<u128>(monkeys, rounds, relief), format),
+    }
+}
+
+/// The monkey holding an item and the item's worry level at the start of a round.
+type ItemState = (usize, usize);
+
+/// Plays one round for a single item and adds its inspections to `counts`.
+///
+/// Items don't affect each other, so following them one at a time gives the same counts
+/// as playing the rounds for all of them together.
+fn play_item_round(
+    monkeys: &[Monkey],
+    (mut holder, mut worry): ItemState,
+    relief: Relief,
+    counts: &mut [usize],
+) -> Result<ItemState, AocError> {
+    loop {
+        let monkey = &monkeys[holder];
+        worry = monkey.inspect_item(None, worry, relief)?;
+        counts[holder] += 1;
+        let target = monkey.throw_target(monkey.test_item(worry)) as usize;
+        // monkeys earlier in the order only get to the item in the next round:
+        if target <= holder {
+            return Ok((target, worry));
+        }
+        holder = target;
+    }
+}
+
+fn fast_forward_item(
+    monkeys: &[Monkey],
+    start: ItemState,
+    rounds: usize,
+    relief: Relief,
+) -> Result<Vec<usize>, AocError> {
+    let mut seen = HashMap::new();
+    // the inspections per monkey during the first `n` rounds are at index `n`:
+    let mut totals = vec![vec![0; monkeys.len()]];
+    let mut state = start;
+    for round in 0..rounds {
+        if let Some(cycle_start) = seen.insert(state, round) {
+            // the rounds from `cycle_start` to `round` repeat until the end:
+            let remaining = rounds - round;
+            let (cycles, rest) = (
+                remaining / (round - cycle_start),
+                remaining % (round - cycle_start),
+            );
+            let (before, after, partial) = (
+                &totals[cycle_start],
+                &totals[round],
+                &totals[cycle_start + rest],
+            );
+            return (0..monkeys.len())
+                .map(|index| {
+                    (after[index] - before[index])
+                        .checked_mul(cycles)
+                        .and_then(|count| count.checked_add(after[index]))
+                        .and_then(|count| count.checked_add(partial[index] - before[index]))
+                        .ok_or_else(|| ArithmeticError::Overflow.into())
+                })
+                .collect();
+        }
+        let mut counts = totals[round].clone();
+        state = play_item_round(monkeys, state, relief, &mut counts)?;
+        totals.push(counts);
+    }
+    Ok(totals
+        .pop()
+        .expect("There are totals for the first zero rounds."))
+}
+
+/// Computes the same counts as [`inspection_counts`] with `usize` worry levels, but skips
+/// ahead as soon as an item is back in a state it has been in before.
+///
+/// With [`Relief::Modulo`] there are only finitely many states, so any number of rounds can
+/// be played in the time it takes to run into a cycle.
+pub fn fast_forward_inspection_counts(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+) -> Result<Vec<usize>, AocError> {
+    let mut counts = vec![0; monkeys.len()];
+    for (holder, monkey) in monkeys.iter().enumerate() {
+        for item in &monkey.items {
+            let item_counts = fast_forward_item(monkeys, (holder, item.worry), rounds, relief)?;
+            for (count, item_count) in counts.iter_mut().zip(item_counts) {
+                *count = count
+                    .checked_add(item_count)
+                    .ok_or(ArithmeticError::Overflow)?;
+            }
+        }
+    }
+    Ok(counts)
+}
+
+/// Worry levels only matter modulo the test conditions, so they can be kept below their lcm.
+pub fn conditions_lcm(monkeys: &[Monkey]) -> Result<usize, AocError> {
+    Ok(monkeys.iter().try_fold(1, |accumulator, monkey| {
+        lcm(accumulator, monkey.test_condition as usize)
+    })?)
+}
+
+fn compute_business_level(inspection_counts: &[usize]) -> usize {
+    inspection_counts.iter().sorted().rev().take(2).product()
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        parse_monkeys(lines)
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Result<usize, AocError> {
+        let counts = inspection_counts(monkeys, 20, Relief::DivideByThree, WorryType::Usize)?;
+        Ok(compute_business_level(&counts))
+    }
+
+    fn part_two(monkeys: &Self::Input) -> Result<usize, AocError> {
+        let relief = Relief::Modulo(conditions_lcm(monkeys)?);
+        let counts = fast_forward_inspection_counts(monkeys, 10000, relief)?;
+        Ok(compute_business_level(&counts))
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::vec;
+
+    use super::*;
+    use crate::read_input;
+
+    fn add(left: Expression, right: Expression) -> Expression {
+        Expression::Add(Box::new(left), Box::new(right))
+    }
+
+    fn subtract(left: Expression, right: Expression) -> Expression {
+        Expression::Subtract(Box::new(left), Box::new(right))
+    }
+
+    fn multiply(left: Expression, right: Expression) -> Expression {
+        Expression::Multiply(Box::new(left), Box::new(right))
+    }
+
+    fn next_throw(throws: &mut VecDeque<Throw<usize>>) -> Option<(usize, usize)> {
+        throws
+            .pop_front()
+            .map(|throw| (throw.item.worry, throw.target))
+    }
+
+    #[test]
+    fn test_parse_starting_items() {
+        assert_eq!(
+            parse_starting_items("  Starting items: 80"),
+            Ok(VecDeque::from_iter(vec![80]))
+        );
+        assert_eq!(
+            parse_starting_items("  Starting items: 75, 83, 74"),
+            Ok(VecDeque::from_iter(vec![75, 83, 74]))
+        );
+        assert_eq!(
+            parse_starting_items("  Starting items: 86, 67, 61, 96, 52, 63, 73"),
+            Ok(VecDeque::from_iter(vec![86, 67, 61, 96, 52, 63, 73]))
+        );
+    }
+
+    #[test]
+    fn test_parse_operation() {
+        assert_eq!(
+            parse_operation("  Operation: new = old * 5"),
+            Ok(multiply(Expression::Old, Expression::Number(5)))
+        );
+        assert_eq!(
+            parse_operation("  Operation: new = old + 7"),
+            Ok(add(Expression::Old, Expression::Number(7)))
+        );
+        assert_eq!(
+            parse_operation("  Operation: new = old * old"),
+            Ok(multiply(Expression::Old, Expression::Old))
+        );
+        assert_eq!(
+            parse_operation("  Operation: new = 2 + old * (old - 3)"),
+            Ok(add(
+                Expression::Number(2),
+                multiply(
+                    Expression::Old,
+                    subtract(Expression::Old, Expression::Number(3))
+                )
+            ))
+        );
+        assert_eq!(
+            parse_operation("  Operation: new = old - 1 - 2"),
+            Ok(subtract(
+                subtract(Expression::Old, Expression::Number(1)),
+                Expression::Number(2)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_operation_error() {
+        assert_eq!(
+            parse_operation("  Operation: new = old * (old + 3"),
+            Err(AocError::parse("  Operation: new = old * (old + 3", "`)`").at_column(33))
+        );
+        assert_eq!(
+            parse_operation("  Operation: new = old / 2"),
+            Err(AocError::parse("  Operation: new = old / 2", "an operator").at_column(23))
+        );
+        assert_eq!(
+            parse_operation("  Operation: new = old *"),
+            Err(
+                AocError::parse("  Operation: new = old *", "`old`, a number or `(`").at_column(24)
+            )
+        );
+        assert_eq!(
+            parse_operation("  Operation: old * 2"),
+            Err(AocError::parse("  Operation: old * 2", OPERATION))
+        );
+    }
+
+    #[test]
+    fn test_evaluate_expression() {
+        let expression = parse_operation("  Operation: new = (old + 1) * (old - 2) - 3").unwrap();
+        assert_eq!(expression.evaluate(5usize), Some(15));
+        assert_eq!(expression.evaluate(0usize), None);
+        assert_eq!(expression.evaluate(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_display_expression() {
+        for expression in [
+            "old * 19",
+            "old * old + 3",
+            "old * (old + 3)",
+            "(old - 1) * 2",
+            "old - (old - 1)",
+            "old - 1 - 2",
+        ] {
+            let line = format!("  Operation: new = {}", expression);
+            let parsed = parse_operation(&line).unwrap();
+            assert_eq!(parsed.to_string(), expression);
+        }
+    }
+
+    #[test]
+    fn parse_parse_test_condition() {
+        assert_eq!(parse_test_condition("  Test: divisible by 2"), Ok(2));
+        assert_eq!(parse_test_condition("  Test: divisible by 11"), Ok(11));
+    }
+
+    #[test]
+    fn test_parse_test_branch() {
+        assert_eq!(
+            parse_test_branch("    If true: throw to monkey 4"),
+            Ok((true, 4))
+        );
+        assert_eq!(
+            parse_test_branch("    If false: throw to monkey 0"),
+            Ok((false, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_monkey() {
+        let lines = read_input("inputs/day11.txt");
+        assert!(lines.is_ok());
+
+        let monkey = parse_monkey(records(&lines.unwrap()).next().unwrap());
+
+        assert_eq!(
+            monkey,
+            Ok(Monkey {
+                id: 0,
+                items: VecDeque::from_iter(vec![Item { id: 0, worry: 80 }]),
+                operation: multiply(Expression::Old, Expression::Number(5)),
+                test_condition: 2,
+                test_branches: [(true, 4), (false, 3)],
+                inspection_count: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_monkey_play_turn() {
+        let lines = read_input("inputs/day11-example.txt");
+        assert!(lines.is_ok());
+
+        let mut monkeys = parse_monkeys(&lines.unwrap()).expect("Monkey 0 should throw items.");
+        let mut turn_result = VecDeque::new();
+        monkeys[0]
+            .play_turn(Relief::DivideByThree, &mut turn_result)
+            .unwrap();
+        catch_items(&mut monkeys, turn_result.clone());
+
+        assert_eq!(next_throw(&mut turn_result), Some((500, 3)));
+        assert_eq!(next_throw(&mut turn_result), Some((620, 3)));
+
+        let mut turn_result = VecDeque::new();
+        monkeys[1]
+            .play_turn(Relief::DivideByThree, &mut turn_result)
+            .unwrap();
+        catch_items(&mut monkeys, turn_result.clone());
+
+        assert_eq!(next_throw(&mut turn_result), Some((20, 0)));
+        assert_eq!(next_throw(&mut turn_result), Some((23, 0)));
+        assert_eq!(next_throw(&mut turn_result), Some((27, 0)));
+        assert_eq!(next_throw(&mut turn_result), Some((26, 0)));
+
+        let mut turn_result = VecDeque::new();
+        monkeys[2]
+            .play_turn(Relief::DivideByThree, &mut turn_result)
+            .unwrap();
+        catch_items(&mut monkeys, turn_result.clone());
+
+        assert_eq!(next_throw(&mut turn_result), Some((2080, 1)));
+        assert_eq!(next_throw(&mut turn_result), Some((1200, 3)));
+        assert_eq!(next_throw(&mut turn_result), Some((3136, 3)));
+
+        let mut turn_result = VecDeque::new();
+        monkeys[3]
+            .play_turn(Relief::DivideByThree, &mut turn_result)
+            .unwrap();
+        catch_items(&mut monkeys, turn_result.clone());
+
+        assert_eq!(next_throw(&mut turn_result), Some((25, 1)));
+        assert_eq!(next_throw(&mut turn_result), Some((167, 1)));
+        assert_eq!(next_throw(&mut turn_result), Some((207, 1)));
+        assert_eq!(next_throw(&mut turn_result), Some((401, 1)));
+        assert_eq!(next_throw(&mut turn_result), Some((1046, 1)));
+    }
+
+    #[test]
+    fn test_unreduced_worry_levels() {
+        let lines = read_input("inputs/day11-example.txt").expect("Couldn't read example input.");
+        let monkeys = parse_monkeys(&lines).expect("Couldn't parse example input.");
+        let modulo = Relief::Modulo(conditions_lcm(&monkeys).unwrap());
+
+        // worry levels overflow a usize in round 13 and a u128 in round 31:
+        assert!(inspection_counts(&monkeys, 12, Relief::Unreduced, WorryType::Usize).is_ok());
+        assert!(inspection_counts(&monkeys, 13, Relief::Unreduced, WorryType::Usize).is_err());
+        assert!(inspection_counts(&monkeys, 31, Relief::Unreduced, WorryType::U128).is_err());
+        for rounds in [1, 20, 30] {
+            assert_eq!(
+                inspection_counts(&monkeys, rounds, Relief::Unreduced, WorryType::U128),
+                inspection_counts(&monkeys, rounds, modulo, WorryType::Usize),
+            );
+        }
+        assert_eq!(
+            inspection_counts(&monkeys, 20, Relief::DivideByThree, WorryType::U128),
+            Ok(vec![101, 95, 7, 105])
+        );
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        let lines = read_input("inputs/day11-example.txt").expect("Couldn't read example input.");
+        let monkeys = parse_monkeys(&lines).expect("Couldn't parse example input.");
+        let modulo = Relief::Modulo(conditions_lcm(&monkeys).unwrap());
+
+        for (rounds, relief) in [
+            (0, modulo),
+            (1, modulo),
+            (1000, modulo),
+            (10000, modulo),
+            (20, Relief::DivideByThree),
+        ] {
+            assert_eq!(
+                fast_forward_inspection_counts(&monkeys, rounds, relief),
+                inspection_counts(&monkeys, rounds, relief, WorryType::Usize)
+            );
+        }
+
+        // every item of the example repeats its holder and worry level every 171 or 448
+        // rounds from round 175 on at the latest, so all of them repeat every 76608 rounds:
+        let (start, period) = (175, 76608);
+        let naive = |rounds| inspection_counts(&monkeys, rounds, modulo, WorryType::Usize).unwrap();
+        let rounds = start + 3 * period + 1234;
+        assert_eq!(
+            fast_forward_inspection_counts(&monkeys, rounds, modulo),
+            Ok(naive(rounds))
+        );
+
+        let rounds = 1_000_000_000_000;
+        let (cycles, rest) = ((rounds - start) / period, (rounds - start) % period);
+        let (prefix, cycle_end, remainder) =
+            (naive(start), naive(start + period), naive(start + rest));
+        let expected = (0..monkeys.len())
+            .map(|monkey| remainder[monkey] + cycles * (cycle_end[monkey] - prefix[monkey]))
+            .collect_vec();
+        assert_eq!(
+            fast_forward_inspection_counts(&monkeys, rounds, modulo),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let lines = read_input("inputs/day11-example.txt").expect("Couldn't read example input.");
+        let monkeys = parse_monkeys(&lines).expect("Couldn't parse example input.");
+        let (trace, result) = trace::<usize>(&monkeys, 20, Relief::DivideByThree);
+        assert_eq!(result, Ok(()));
+
+        assert_eq!(trace.round_counts[0], vec![2, 4, 3, 5]);
+        let totals = (0..monkeys.len())
+            .map(|monkey| {
+                trace
+                    .round_counts
+                    .iter()
+                    .map(|counts| counts[monkey])
+                    .sum::<usize>()
+            })
+            .collect_vec();
+        assert_eq!(totals, vec![101, 95, 7, 105]);
+        assert_eq!(
+            trace.inspections[0],
+            Inspection {
+                round: 1,
+                monkey: 0,
+                item: 0,
+                worry_before: 79,
+                worry_after: 500,
+                test_result: false,
+                target: 3
+            }
+        );
+        // item 0 is inspected by monkey 3 in the same round:
+        assert_eq!(
+            trace
+                .inspections
+                .iter()
+                .filter(|inspection| inspection.round == 1 && inspection.item == 0)
+                .map(|inspection| inspection.monkey)
+                .collect_vec(),
+            vec![0, 3]
+        );
+
+        let first_round = Trace {
+            inspections: trace.inspections[..1].to_vec(),
+            round_counts: trace.round_counts[..1].to_vec(),
+        };
+        assert_eq!(
+            first_round.inspections_csv(),
+            "round,monkey,item,worry_before,worry_after,test_result,target\n1,0,0,79,500,false,3\n"
+        );
+        assert_eq!(
+            first_round.round_counts_csv(),
+            "round,monkey,inspections\n1,0,2\n1,1,4\n1,2,3\n1,3,5\n"
+        );
+        assert_eq!(
+            first_round.to_json_lines(),
+            "{\"type\":\"inspection\",\"round\":1,\"monkey\":0,\"item\":0,\"worry_before\":79,\"worry_after\":500,\"test_result\":false,\"target\":3}\n\
+             {\"type\":\"round\",\"round\":1,\"inspections\":[2,4,3,5]}\n"
+        );
+    }
+
+    #[test]
+    fn test_export_trace() {
+        let lines = read_input("inputs/day11-example.txt").expect("Couldn't read example input.");
+        let monkeys = parse_monkeys(&lines).expect("Couldn't parse example input.");
+        let counts = export_trace(
+            &monkeys,
+            2,
+            Relief::DivideByThree,
+            WorryType::Usize,
+            TraceFormat::RoundCountsCsv,
+        );
+        assert_eq!(
+            counts,
+            (
+                "round,monkey,inspections\n1,0,2\n1,1,4\n1,2,3\n1,3,5\n\
+                 2,0,4\n2,1,6\n2,2,1\n2,3,5\n"
+                    .to_string(),
+                Ok(())
+            )
+        );
+        let (json_lines, _) = export_trace(
+            &monkeys,
+            1,
+            Relief::DivideByThree,
+            WorryType::U128,
+            TraceFormat::JsonLines,
+        );
+        assert_eq!(json_lines.lines().count(), 14 + 1);
+
+        // unreduced worry levels overflow usize within 20 rounds, but u128 lasts longer:
+        let export = |worry_type| {
+            export_trace(
+                &monkeys,
+                20,
+                Relief::Unreduced,
+                worry_type,
+                TraceFormat::InspectionsCsv,
+            )
+        };
+        let (usize_csv, usize_result) = export(WorryType::Usize);
+        let (u128_csv, u128_result) = export(WorryType::U128);
+        assert_eq!(u128_result, Ok(()));
+        // everything up to the overflow is kept:
+        assert!(usize_csv.lines().count() > 100);
+        assert!(u128_csv.starts_with(&usize_csv));
+        assert_eq!(
+            usize_result,
+            Err(AocError::solve(
+                "Monkey 0 overflows inspecting item 0: applying `new = old * 19` to \
+                 old = 13988703546165100909 gives a worry level out of range."
+            ))
+        );
+        // the last inspection of item 0 led to the worry level that overflows:
+        let last_inspection = usize_csv
+            .lines()
+            .rev()
+            .find(|line| line.split(',').nth(2) == Some("0"))
+            .unwrap();
+        assert!(last_inspection.contains(",13988703546165100909,"));
+    }
+
+    fn monkey_lines(id: usize, targets: [usize; 2]) -> Vec<String> {
+        vec![
+            format!("Monkey {}:", id),
+            format!("  Starting items: {}", 10 + id),
+            "  Operation: new = old + 1".to_string(),
+            "  Test: divisible by 2".to_string(),
+            format!("    If true: throw to monkey {}", targets[0]),
+            format!("    If false: throw to monkey {}", targets[1]),
+        ]
+    }
+
+    fn join_monkeys(monkeys: &[Vec<String>]) -> Vec<String> {
+        monkeys.join(&String::new())
+    }
+
+    #[test]
+    fn test_parse_monkeys_by_id() {
+        let in_order = join_monkeys(&[
+            monkey_lines(0, [1, 2]),
+            monkey_lines(1, [2, 0]),
+            monkey_lines(2, [0, 1]),
+        ]);
+        let shuffled = join_monkeys(&[
+            monkey_lines(2, [0, 1]),
+            monkey_lines(0, [1, 2]),
+            monkey_lines(1, [2, 0]),
+        ]);
+        let (in_order, shuffled) = (
+            parse_monkeys(&in_order).unwrap(),
+            parse_monkeys(&shuffled).unwrap(),
+        );
+        assert_eq!(
+            in_order.iter().map(|monkey| monkey.id).collect_vec(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            shuffled.iter().map(|monkey| monkey.id).collect_vec(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            inspection_counts(&in_order, 20, Relief::DivideByThree, WorryType::Usize),
+            inspection_counts(&shuffled, 20, Relief::DivideByThree, WorryType::Usize)
+        );
+        // items are numbered in the order they appear in the input:
+        assert_eq!(shuffled[2].items[0], Item { id: 0, worry: 12 });
+    }
+
+    #[test]
+    fn test_parse_monkeys_validation() {
+        let duplicate = join_monkeys(&[
+            monkey_lines(0, [1, 1]),
+            monkey_lines(1, [0, 0]),
+            monkey_lines(1, [0, 0]),
+        ]);
+        assert_eq!(
+            parse_monkeys(&duplicate),
+            Err(AocError::parse(
+                "Monkey 1:",
+                "a monkey id other than 1, which is already taken"
+            )
+            .at_line(14))
+        );
+        let missing = join_monkeys(&[monkey_lines(0, [2, 2]), monkey_lines(2, [0, 0])]);
+        assert_eq!(
+            parse_monkeys(&missing),
+            Err(AocError::parse(
+                "Monkey 2:",
+                "a monkey id below 2, since monkey 1 is missing"
+            )
+            .at_line(7))
+        );
+        let gap = join_monkeys(&[
+            monkey_lines(0, [1, 3]),
+            monkey_lines(1, [3, 0]),
+            monkey_lines(3, [0, 1]),
+        ]);
+        assert_eq!(
+            parse_monkeys(&gap).unwrap_err().to_string(),
+            "line 15: expected a monkey id below 3, since monkey 2 is missing, found `Monkey 3:`"
+        );
+        let self_throw = join_monkeys(&[monkey_lines(0, [1, 1]), monkey_lines(1, [0, 1])]);
+        assert_eq!(
+            parse_monkeys(&self_throw),
+            Err(
+                AocError::parse("    If false: throw to monkey 1", "a monkey other than 1")
+                    .at_line(12)
+            )
+        );
+        let unknown = join_monkeys(&[monkey_lines(0, [1, 1]), monkey_lines(1, [5, 0])]);
+        assert_eq!(
+            parse_monkeys(&unknown),
+            Err(
+                AocError::parse("    If true: throw to monkey 5", "a monkey between 0 and 1")
+                    .at_line(11)
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate_branches() {
+        for (when, other) in [("true", "false"), ("false", "true")] {
+            let mut lines = join_monkeys(&[monkey_lines(0, [1, 1]), monkey_lines(1, [0, 0])]);
+            lines[4] = format!("    If {}: throw to monkey 1", when);
+            lines[5] = format!("    If {}: throw to monkey 1", when);
+            assert_eq!(
+                parse_monkeys(&lines),
+                Err(AocError::parse(&lines[5], format!("`If {}:`", other)).at_line(5))
+            );
+        }
+        // the order of the branches doesn't matter:
+        let mut lines = join_monkeys(&[monkey_lines(0, [1, 1]), monkey_lines(1, [0, 0])]);
+        lines.swap(4, 5);
+        assert!(parse_monkeys(&lines).is_ok());
+    }
+
+    #[test]
+    fn test_parse_monkeys_error() {
+        let lines = [
+            "Monkey 0:",
+            "  Starting items: 79, 98",
+            "  Operation: new = old * 19",
+            "  Test: divisable by 23",
+        ]
+        .map(str::to_string);
+
+        let error = parse_monkeys(&lines).unwrap_err();
+
+        assert_eq!(
+            error.in_file("day11").to_string(),
+            "day11 line 4: expected `Test: divisible by N`, found `  Test: divisable by 23`"
+        );
+        assert_eq!(
+            parse_monkeys(&lines[..3]),
+            Err(AocError::unexpected_end(TEST_CONDITION))
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::grid::{Grid, Point};
+use crate::search::{astar, bfs, Path};
+use crate::solution::Solution;
+use crate::AocError;
+
+pub struct Day12;
+
+type Value = usize;
+type Position = Point;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Heightmap {
+    heights: Grid<Value>,
+    start: Position,
+    finish: Position,
+}
+
+impl Heightmap {
+    fn new(input: &[String]) -> Result<Heightmap, AocError> {
+        let cells = Grid::parse(input, "a height from `a` to `z`", |char| match char {
+            'S' | 'E' | 'a'..='z' => Some(char),
+            _ => None,
+        })?;
+        let start = find_unique(&cells, 'S', input, "a single start point")?
+            .ok_or_else(|| AocError::unexpected_end("a start point `S`"))?;
+        let finish = find_unique(&cells, 'E', input, "a single finish point")?
+            .ok_or_else(|| AocError::unexpected_end("a finish point `E`"))?;
+        Ok(Heightmap {
+            heights: cells.map(|char| height(*char)),
+            start,
+            finish,
+        })
+    }
+
+    fn neighbors(&self, position: Position) -> Vec<Position> {
+        self.heights.neighbours_4(position).collect()
+    }
+
+    fn height(&self, position: Position) -> usize {
+        self.heights[position]
+    }
+
+    fn can_climb(&self, from: Position, to: Position) -> bool {
+        self.height(to) <= self.height(from) + 1
+    }
+
+    fn shortest_path(&self, from: Position, to: Position) -> Option<Path<Position, usize>> {
+        astar(
+            from,
+            |&position| {
+                self.neighbors(position)
+                    .into_iter()
+                    .filter(move |neighbor| self.can_climb(position, *neighbor))
+                    .map(|neighbor| (neighbor, 1))
+            },
+            |position| position.manhattan_distance(&to),
+            |position| *position == to,
+        )
+    }
+
+    /// Walks down from the finish to the closest square of the lowest height, which is the
+    /// same as the shortest trail from any of those squares up to the finish.
+    fn shortest_hiking_trail(&self) -> Option<Path<Position, usize>> {
+        let mut trail = bfs(
+            self.finish,
+            |&position| {
+                self.neighbors(position)
+                    .into_iter()
+                    .filter(move |neighbor| self.can_climb(*neighbor, position))
+            },
+            |position| self.height(*position) == 0,
+        )?;
+        trail.nodes.reverse();
+        Some(trail)
+    }
+}
+
+fn height(char: char) -> Value {
+    match char {
+        'S' => 0,
+        'E' => 25,
+        _ => char as Value - 'a' as Value,
+    }
+}
+
+fn find_unique(
+    cells: &Grid<char>,
+    what: char,
+    input: &[String],
+    expected: &str,
+) -> Result<Option<Position>, AocError> {
+    let mut positions = cells
+        .iter()
+        .filter(|(_, char)| **char == what)
+        .map(|(position, _)| position);
+    let first = positions.next();
+    match positions.next() {
+        Some(second) => Err(AocError::parse(&input[second.row], expected)
+            .at_line(second.row)
+            .at_column(second.column)),
+        None => Ok(first),
+    }
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Heightmap;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        Heightmap::new(lines)
+    }
+
+    fn part_one(heightmap: &Self::Input) -> Result<usize, AocError> {
+        heightmap
+            .shortest_path(heightmap.start, heightmap.finish)
+            .map(|path| path.cost)
+            .ok_or_else(|| AocError::solve("There is no path from the start to the finish."))
+    }
+
+    fn part_two(heightmap: &Self::Input) -> Result<usize, AocError> {
+        heightmap
+            .shortest_hiking_trail()
+            .map(|trail| trail.cost)
+            .ok_or_else(|| AocError::solve("There is no trail to the finish."))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn lines(input: &[&str]) -> Vec<String> {
+        input.iter().map(<&str>::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_heightmap() {
+        let heightmap = Heightmap::new(&lines(&["Sbc", "yzE"])).unwrap();
+        assert_eq!(
+            heightmap.heights.row(1).copied().collect::<Vec<_>>(),
+            [24, 25, 25]
+        );
+        assert_eq!(heightmap.start, Point::new(0, 0));
+        assert_eq!(heightmap.finish, Point::new(1, 2));
+        assert!(Heightmap::new(&lines(&["Sb1", "yzE"])).is_err());
+        assert!(Heightmap::new(&lines(&["Sbc", "yzS", "E"])).is_err());
+        assert_eq!(
+            Heightmap::new(&lines(&["SbE", "yzE"])).map_err(|err| err.to_string()),
+            Err("line 2 column 3: expected a single finish point, found `yzE`".to_string())
+        );
+        assert!(Heightmap::new(&lines(&["abc", "yzE"])).is_err());
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let lines = crate::read_input("inputs/day12-example.txt").unwrap();
+        let heightmap = Heightmap::new(&lines).unwrap();
+        let path = heightmap
+            .shortest_path(heightmap.start, heightmap.finish)
+            .unwrap();
+        assert_eq!(path.cost, 31);
+        assert_eq!(path.nodes.len(), 32);
+        assert_eq!(path.nodes.first(), Some(&heightmap.start));
+        let trail = heightmap.shortest_hiking_trail().unwrap();
+        assert_eq!(trail.cost, 29);
+        assert_eq!(trail.nodes.last(), Some(&heightmap.finish));
+        assert_eq!(heightmap.height(trail.nodes[0]), 0);
+
+        let heightmap = Heightmap::new(&["Sbz".to_string(), "acE".to_string()]).unwrap();
+        assert_eq!(
+            heightmap.shortest_path(heightmap.start, heightmap.finish),
+            None
+        );
+    }
+
+    #[test]
+    fn test_heightmap_neighbors() {
+        let heightmap = Heightmap {
+            heights: Grid::new(3, 3, 0),
+            start: Point::new(0, 0),
+            finish: Point::new(2, 2),
+        };
+        let neighbors = |row, column| {
+            heightmap
+                .neighbors(Point::new(row, column))
+                .into_iter()
+                .map(|point| (point.row, point.column))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(neighbors(0, 0), vec![(0, 1), (1, 0)]);
+        assert_eq!(neighbors(1, 0), vec![(0, 0), (1, 1), (2, 0)]);
+        assert_eq!(neighbors(2, 0), vec![(1, 0), (2, 1)]);
+
+        assert_eq!(neighbors(0, 1), vec![(0, 0), (0, 2), (1, 1)]);
+        assert_eq!(neighbors(1, 1), vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+        assert_eq!(neighbors(2, 1), vec![(1, 1), (2, 0), (2, 2)]);
+
+        assert_eq!(neighbors(0, 2), vec![(0, 1), (1, 2)]);
+        assert_eq!(neighbors(1, 2), vec![(0, 2), (1, 1), (2, 2)]);
+        assert_eq!(neighbors(2, 2), vec![(1, 2), (2, 1)]);
+    }
+}
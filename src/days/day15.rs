@@ -0,0 +1,238 @@
+use crate::interval::{Interval, IntervalSet};
+use crate::solution::Solution;
+use crate::{AocError, InputKind};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+pub struct Day15;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+fn parse_line(input: &str) -> Result<(Position, Position), AocError> {
+    lazy_static!(
+      static ref RE: Regex = Regex::new(r"Sensor at x=(?P<SensorX>-?\d+), y=(?P<SensorY>-?\d+): closest beacon is at x=(?P<BeaconX>-?\d+), y=(?P<BeaconY>-?\d+)").unwrap();
+    );
+    let error = || AocError::parse(input, "`Sensor at x=N, y=N: closest beacon is at x=N, y=N`");
+    let captures = RE.captures(input).ok_or_else(error)?;
+    let coordinate = |name: &str| -> Result<i64, AocError> {
+        let capture = captures.name(name).ok_or_else(error)?;
+        capture
+            .as_str()
+            .parse::<i64>()
+            .map_err(|_| AocError::parse(input, "a coordinate").at_column(capture.start()))
+    };
+
+    Ok((
+        Position {
+            x: coordinate("SensorX")?,
+            y: coordinate("SensorY")?,
+        },
+        Position {
+            x: coordinate("BeaconX")?,
+            y: coordinate("BeaconY")?,
+        },
+    ))
+}
+
+fn manhattan_distance(first: &Position, second: &Position) -> usize {
+    ((second.x - first.x).abs() + (second.y - first.y).abs()) as usize
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sensor {
+    position: Position,
+    beacon: Position,
+    range: usize,
+}
+
+impl Sensor {
+    fn new((position, beacon): (Position, Position)) -> Sensor {
+        Sensor {
+            range: manhattan_distance(&position, &beacon),
+            position,
+            beacon,
+        }
+    }
+
+    fn no_beacon_range(&self, y: i64) -> Option<Interval<i64>> {
+        let width = self.range as i64 - (y - self.position.y).abs();
+        Interval::new(self.position.x - width, self.position.x + width)
+    }
+}
+
+fn covered_positions(sensors: &[Sensor], y: i64) -> IntervalSet<i64> {
+    sensors
+        .iter()
+        .filter_map(|sensor| sensor.no_beacon_range(y))
+        .collect()
+}
+
+fn count_no_beacon_positions(sensors: &[Sensor], y: i64) -> Option<usize> {
+    let covered = covered_positions(sensors, y);
+    let beacons = sensors
+        .iter()
+        .map(|sensor| &sensor.beacon)
+        .filter(|beacon| beacon.y == y && covered.contains(beacon.x))
+        .map(|beacon| beacon.x)
+        .collect::<HashSet<_>>();
+    Some(covered.len()? as usize - beacons.len())
+}
+
+fn find_beacon(sensors: &[Sensor], search_area: Interval<i64>) -> Option<Position> {
+    let area = IntervalSet::from(search_area);
+    (search_area.start()..=search_area.end()).find_map(|y| {
+        // the only position in the area that isn't covered by any sensor must be the beacon:
+        let uncovered = area.difference(&covered_positions(sensors, y));
+        uncovered.intervals().first().map(|interval| Position {
+            x: interval.start(),
+            y,
+        })
+    })
+}
+
+fn tuning_frequency(position: Position) -> i64 {
+    position.x * 4000000 + position.y
+}
+
+/// Sensors together with the puzzle parameters, which differ between the example and
+/// the real input.
+pub struct Scan {
+    sensors: Vec<Sensor>,
+    row: i64,
+    search_area: Interval<i64>,
+}
+
+impl Scan {
+    fn new(sensors: Vec<Sensor>, kind: InputKind) -> Scan {
+        let (row, search_limit) = match kind {
+            InputKind::Example => (10, 20),
+            InputKind::Real => (2000000, 4000000),
+        };
+        Scan {
+            sensors,
+            row,
+            search_area: Interval::new(0, search_limit).expect("The search area isn't empty."),
+        }
+    }
+}
+
+fn parse_sensors(lines: &[String]) -> Result<Vec<Sensor>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            parse_line(line)
+                .map(Sensor::new)
+                .map_err(|err| err.at_line(index))
+        })
+        .collect()
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Scan;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        Self::parse_kind(lines, InputKind::Real)
+    }
+
+    fn parse_kind(lines: &[String], kind: InputKind) -> Result<Self::Input, AocError> {
+        parse_sensors(lines).map(|sensors| Scan::new(sensors, kind))
+    }
+
+    fn part_one(scan: &Self::Input) -> Result<usize, AocError> {
+        count_no_beacon_positions(&scan.sensors, scan.row)
+            .ok_or_else(|| AocError::solve("The covered positions are too many to count."))
+    }
+
+    fn part_two(scan: &Self::Input) -> Result<i64, AocError> {
+        let beacon_position = find_beacon(&scan.sensors, scan.search_area)
+            .ok_or_else(|| AocError::solve("Couldn't find beacon."))?;
+        Ok(tuning_frequency(beacon_position))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn load_example_input() -> Vec<Sensor> {
+        let lines = read_input("inputs/day15-example.txt").expect("Couldn't read from input file.");
+        parse_sensors(&lines).expect("Couldn't parse input line")
+    }
+
+    #[test]
+    fn test_load_input() {
+        let sensors = load_example_input();
+        assert_eq!(sensors.len(), 14);
+        assert_eq!(
+            sensors[0],
+            Sensor {
+                position: Position { x: 2, y: 18 },
+                beacon: Position { x: -2, y: 15 },
+                range: 7
+            }
+        );
+        assert_eq!(
+            sensors[12],
+            Sensor {
+                position: Position { x: 14, y: 3 },
+                beacon: Position { x: 15, y: 3 },
+                range: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_beacon_range() {
+        let sensors = load_example_input();
+        assert_eq!(sensors[6].no_beacon_range(-3), None);
+        assert_eq!(sensors[6].no_beacon_range(-2), Interval::new(8, 8));
+        assert_eq!(sensors[6].no_beacon_range(0), Interval::new(6, 10));
+        assert_eq!(sensors[6].no_beacon_range(7), Interval::new(-1, 17));
+        assert_eq!(sensors[6].no_beacon_range(16), Interval::new(8, 8));
+        assert_eq!(sensors[6].no_beacon_range(17), None);
+    }
+
+    #[test]
+    fn test_count_no_beacon_positions() {
+        assert_eq!(
+            count_no_beacon_positions(&load_example_input(), 10),
+            Some(26)
+        );
+    }
+
+    #[test]
+    fn test_parameters() {
+        // the parameters come from the kind of input, not from the size of the coordinates:
+        let lines = vec!["Sensor at x=2, y=18: closest beacon is at x=-2, y=15".to_string()];
+        let scan = Day15::parse_kind(&lines, InputKind::Example).unwrap();
+        assert_eq!(
+            (scan.row, scan.search_area),
+            (10, Interval::new(0, 20).unwrap())
+        );
+        let scan = Day15::parse(&lines).unwrap();
+        assert_eq!(
+            (scan.row, scan.search_area),
+            (2000000, Interval::new(0, 4000000).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_find_beacon() {
+        let search_area = Interval::new(0, 20).unwrap();
+        assert_eq!(
+            find_beacon(&load_example_input(), search_area),
+            Some(Position { x: 14, y: 11 })
+        );
+    }
+}
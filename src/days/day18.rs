@@ -0,0 +1,64 @@
+use crate::solution::Solution;
+use crate::voxel::{flood_fill, Point3, VoxelSet, OFFSETS_6};
+use crate::AocError;
+use itertools::Itertools;
+
+pub struct Day18;
+
+fn parse_position(input: &str) -> Result<Point3, AocError> {
+    let coordinates = input.split(',').collect_vec();
+    if coordinates.len() != 3 {
+        return Err(AocError::parse(input, "three comma separated coordinates"));
+    }
+    let mut column = 0;
+    let mut values = [0; 3];
+    for (value, coordinate) in values.iter_mut().zip(coordinates) {
+        *value = coordinate
+            .parse::<i64>()
+            .map_err(|_| AocError::parse(input, "a coordinate").at_column(column))?;
+        column += coordinate.len() + 1;
+    }
+    let [x, y, z] = values;
+
+    Ok(Point3::new(x, y, z))
+}
+
+/// Fills the air around the droplet and counts the faces where it bumps into lava.
+fn compute_outer_surface_area(droplet: &VoxelSet) -> usize {
+    let Some(bounds) = droplet.bounding_box() else {
+        return 0;
+    };
+    let bounds = bounds.enlarge(1);
+    flood_fill(bounds.min, &bounds, &OFFSETS_6, |cube| {
+        !droplet.contains(cube)
+    })
+    .boundary
+    .len()
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = VoxelSet;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        if lines.is_empty() {
+            return Err(AocError::unexpected_end("at least one position"));
+        }
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| parse_position(line).map_err(|err| err.at_line(index)))
+            .collect()
+    }
+
+    fn part_one(droplet: &Self::Input) -> Result<usize, AocError> {
+        Ok(droplet.surface_area())
+    }
+
+    fn part_two(droplet: &Self::Input) -> Result<usize, AocError> {
+        Ok(compute_outer_surface_area(droplet))
+    }
+}
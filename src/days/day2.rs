@@ -0,0 +1,165 @@
+use crate::solution::Solution;
+use crate::AocError;
+
+pub struct Day2;
+
+#[derive(Clone, Copy)]
+enum OpponentChoice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Clone, Copy)]
+enum MyChoice {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+fn my_choice_score(choice: &MyChoice) -> u32 {
+    *choice as u32
+}
+
+#[derive(Clone, Copy)]
+enum RoundResult {
+    Win = 6,
+    Lose = 0,
+    Draw = 3,
+}
+
+fn round_result_score(result: RoundResult) -> u32 {
+    result as u32
+}
+
+fn round_result(my_choice: MyChoice, opponent_choice: OpponentChoice) -> RoundResult {
+    match (my_choice, opponent_choice) {
+        (MyChoice::Paper, OpponentChoice::Paper) => RoundResult::Draw,
+        (MyChoice::Paper, OpponentChoice::Rock) => RoundResult::Win,
+        (MyChoice::Paper, OpponentChoice::Scissors) => RoundResult::Lose,
+        (MyChoice::Rock, OpponentChoice::Paper) => RoundResult::Lose,
+        (MyChoice::Rock, OpponentChoice::Rock) => RoundResult::Draw,
+        (MyChoice::Rock, OpponentChoice::Scissors) => RoundResult::Win,
+        (MyChoice::Scissors, OpponentChoice::Paper) => RoundResult::Win,
+        (MyChoice::Scissors, OpponentChoice::Rock) => RoundResult::Lose,
+        (MyChoice::Scissors, OpponentChoice::Scissors) => RoundResult::Draw,
+    }
+}
+
+fn parse_my_choice(input: &char) -> Option<MyChoice> {
+    match input {
+        'X' => Some(MyChoice::Rock),
+        'Y' => Some(MyChoice::Paper),
+        'Z' => Some(MyChoice::Scissors),
+        _ => None,
+    }
+}
+
+fn parse_opponent_choice(input: &char) -> Option<OpponentChoice> {
+    match input {
+        'A' => Some(OpponentChoice::Rock),
+        'B' => Some(OpponentChoice::Paper),
+        'C' => Some(OpponentChoice::Scissors),
+        _ => None,
+    }
+}
+
+fn parse_desired_outcome(input: &char) -> Option<RoundResult> {
+    match input {
+        'X' => Some(RoundResult::Lose),
+        'Y' => Some(RoundResult::Draw),
+        'Z' => Some(RoundResult::Win),
+        _ => None,
+    }
+}
+
+enum Part {
+    One,
+    Two,
+}
+
+fn result(my_choice: MyChoice, round_result: RoundResult) -> u32 {
+    my_choice_score(&my_choice) + round_result_score(round_result)
+}
+
+fn my_choice_part_two(opponent_choice: OpponentChoice, round_result: RoundResult) -> MyChoice {
+    match (opponent_choice, round_result) {
+        (OpponentChoice::Paper, RoundResult::Draw) => MyChoice::Paper,
+        (OpponentChoice::Rock, RoundResult::Draw) => MyChoice::Rock,
+        (OpponentChoice::Scissors, RoundResult::Draw) => MyChoice::Scissors,
+        (OpponentChoice::Paper, RoundResult::Win) => MyChoice::Scissors,
+        (OpponentChoice::Rock, RoundResult::Win) => MyChoice::Paper,
+        (OpponentChoice::Scissors, RoundResult::Win) => MyChoice::Rock,
+        (OpponentChoice::Paper, RoundResult::Lose) => MyChoice::Rock,
+        (OpponentChoice::Rock, RoundResult::Lose) => MyChoice::Scissors,
+        (OpponentChoice::Scissors, RoundResult::Lose) => MyChoice::Paper,
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Round {
+    opponent_choice: OpponentChoice,
+    my_choice: MyChoice,
+    desired_outcome: RoundResult,
+}
+
+fn parse_line(line: &str) -> Result<Round, AocError> {
+    let characters = line.chars().collect::<Vec<_>>();
+    let opponent_choice = characters
+        .first()
+        .and_then(parse_opponent_choice)
+        .ok_or_else(|| AocError::parse(line, "one of `A`, `B` or `C`").at_column(0))?;
+    if characters.get(1) != Some(&' ') {
+        return Err(AocError::parse(line, "a space").at_column(1));
+    }
+    let second_character = characters
+        .get(2)
+        .ok_or_else(|| AocError::parse(line, "one of `X`, `Y` or `Z`").at_column(2))?;
+    match (
+        parse_my_choice(second_character),
+        parse_desired_outcome(second_character),
+    ) {
+        (Some(my_choice), Some(desired_outcome)) => Ok(Round {
+            opponent_choice,
+            my_choice,
+            desired_outcome,
+        }),
+        _ => Err(AocError::parse(line, "one of `X`, `Y` or `Z`").at_column(2)),
+    }
+}
+
+fn score(round: Round, part: Part) -> u32 {
+    let round_result = match part {
+        Part::One => round_result(round.my_choice, round.opponent_choice),
+        Part::Two => round.desired_outcome,
+    };
+    let my_choice = match part {
+        Part::One => round.my_choice,
+        Part::Two => my_choice_part_two(round.opponent_choice, round_result),
+    };
+    result(my_choice, round_result)
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Round>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| parse_line(line).map_err(|err| err.at_line(index)))
+            .collect()
+    }
+
+    fn part_one(rounds: &Self::Input) -> Result<u32, AocError> {
+        Ok(rounds.iter().map(|round| score(*round, Part::One)).sum())
+    }
+
+    fn part_two(rounds: &Self::Input) -> Result<u32, AocError> {
+        Ok(rounds.iter().map(|round| score(*round, Part::Two)).sum())
+    }
+}
@@ -0,0 +1,281 @@
+#[cfg(test)]
+use crate::circular_list::wrap_index;
+use crate::circular_list::CircularList;
+use crate::solution::Solution;
+use crate::AocError;
+use itertools::Itertools;
+
+pub struct Day20;
+
+fn parse_input(input: &[String]) -> Result<Vec<i64>, AocError> {
+    let numbers = input
+        .iter()
+        .enumerate()
+        .map(|(index, v)| {
+            v.parse::<i64>()
+                .map_err(|_| AocError::parse(v, "a number").at_line(index))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut zeros = numbers.iter().positions(|v| *v == 0);
+    let Some(first) = zeros.next() else {
+        return Err(AocError::unexpected_end("a line with `0`"));
+    };
+    if let Some(second) = zeros.next() {
+        let expected = format!(
+            "a number other than 0, which is in line {} already",
+            first + 1
+        );
+        return Err(AocError::parse(&input[second], expected).at_line(second));
+    }
+    Ok(numbers)
+}
+
+#[cfg(test)]
+fn to_indexed_numbers(input: Vec<i64>) -> Vec<(usize, i64)> {
+    input.into_iter().enumerate().collect_vec()
+}
+
+/// Moves a single number in linear time, which is kept as a reference for [`mix`].
+#[cfg(test)]
+fn do_step(numbers: &mut Vec<(usize, i64)>, index_to_move: usize) {
+    let position = numbers
+        .iter()
+        .position(|(i, _)| *i == index_to_move)
+        .expect("Can't find number!");
+    let element_to_move = numbers.remove(position);
+    let new_position = wrap_index(position as i64 + element_to_move.1, numbers.len());
+    numbers.insert(new_position, element_to_move);
+}
+
+#[cfg(test)]
+fn index_of_zero(numbers: &[(usize, i64)]) -> usize {
+    numbers
+        .iter()
+        .position(|(_, v)| *v == 0)
+        .expect("Couldn't find zero!")
+}
+
+/// How to decrypt the grove coordinates.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Decryption {
+    /// Every number is multiplied by the key before mixing.
+    pub key: i128,
+    pub rounds: usize,
+    /// The coordinates are the numbers this many steps after the zero.
+    pub offsets: Vec<i64>,
+}
+
+impl Decryption {
+    pub fn part_one() -> Decryption {
+        Decryption {
+            key: 1,
+            rounds: 1,
+            offsets: vec![1000, 2000, 3000],
+        }
+    }
+
+    pub fn part_two() -> Decryption {
+        Decryption {
+            key: 811589153,
+            rounds: 10,
+            ..Decryption::part_one()
+        }
+    }
+}
+
+impl Default for Decryption {
+    fn default() -> Self {
+        Decryption::part_one()
+    }
+}
+
+/// Moves every number by its value, in the order the handles were created.
+fn mix(numbers: &mut CircularList<i128>) {
+    // a number passes all others after `len - 1` steps, so only the remainder matters:
+    let full_turn = (numbers.len() as i128 - 1).max(1);
+    for handle in numbers.handles().sorted().collect_vec() {
+        numbers.move_by(handle, numbers[handle].rem_euclid(full_turn) as i64);
+    }
+}
+
+/// Sums the grove coordinates after mixing the numbers as described by `decryption`.
+pub fn decrypt(numbers: &[i64], decryption: &Decryption) -> Result<i128, AocError> {
+    let mut numbers = numbers
+        .iter()
+        .map(|value| {
+            (*value as i128).checked_mul(decryption.key).ok_or_else(|| {
+                AocError::solve(format!(
+                    "Applying the key {} to {} overflows.",
+                    decryption.key, value
+                ))
+            })
+        })
+        .collect::<Result<CircularList<_>, _>>()?;
+    for _round in 0..decryption.rounds {
+        mix(&mut numbers);
+    }
+    let zero = numbers
+        .find(|value| *value == 0)
+        .ok_or_else(|| AocError::solve("There is no zero to start from."))?;
+    decryption.offsets.iter().try_fold(0i128, |sum, offset| {
+        sum.checked_add(numbers[numbers.relative(zero, *offset)])
+            .ok_or_else(|| AocError::solve("The sum of the grove coordinates overflows."))
+    })
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Vec<i64>;
+    type PartOne = i128;
+    type PartTwo = i128;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        parse_input(lines)
+    }
+
+    fn part_one(numbers: &Self::Input) -> Result<i128, AocError> {
+        decrypt(numbers, &Decryption::part_one())
+    }
+
+    fn part_two(numbers: &Self::Input) -> Result<i128, AocError> {
+        decrypt(numbers, &Decryption::part_two())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::read_input;
+
+    #[test]
+    fn test_example_input() {
+        let input =
+            parse_input(&read_input("inputs/day20-example.txt").expect("Coulnd't read input"))
+                .expect("Couldn't parse input");
+        let mut numbers = to_indexed_numbers(input);
+
+        do_step(&mut numbers, 0);
+        assert_eq!(
+            numbers,
+            vec![(1, 2), (0, 1), (2, -3), (3, 3), (4, -2), (5, 0), (6, 4)]
+        );
+        do_step(&mut numbers, 1);
+        assert_eq!(
+            numbers,
+            vec![(0, 1), (2, -3), (1, 2), (3, 3), (4, -2), (5, 0), (6, 4)]
+        );
+        do_step(&mut numbers, 2);
+        assert_eq!(
+            numbers,
+            vec![(0, 1), (1, 2), (3, 3), (4, -2), (2, -3), (5, 0), (6, 4)]
+        );
+        do_step(&mut numbers, 3);
+        assert_eq!(
+            numbers,
+            vec![(0, 1), (1, 2), (4, -2), (2, -3), (5, 0), (3, 3), (6, 4)]
+        );
+        do_step(&mut numbers, 4);
+        assert_eq!(
+            numbers,
+            vec![(4, -2), (0, 1), (1, 2), (2, -3), (5, 0), (3, 3), (6, 4)]
+        );
+        do_step(&mut numbers, 5);
+        assert_eq!(
+            numbers,
+            vec![(4, -2), (0, 1), (1, 2), (2, -3), (5, 0), (3, 3), (6, 4)]
+        );
+        do_step(&mut numbers, 6);
+        assert_eq!(
+            numbers,
+            vec![(4, -2), (0, 1), (1, 2), (2, -3), (6, 4), (5, 0), (3, 3)]
+        );
+
+        let zero_index = index_of_zero(&numbers);
+        assert_eq!(wrap_index((zero_index + 1000) as i64, numbers.len()), 4);
+        assert_eq!(wrap_index((zero_index + 2000) as i64, numbers.len()), 3);
+        assert_eq!(wrap_index((zero_index + 3000) as i64, numbers.len()), 2);
+    }
+
+    #[test]
+    fn test_parse_zeros() {
+        let lines = ["1", "0", "-2", "0"].map(str::to_string);
+        assert_eq!(parse_input(&lines[..3]), Ok(vec![1, 0, -2]));
+        assert_eq!(
+            parse_input(&lines),
+            Err(
+                AocError::parse("0", "a number other than 0, which is in line 2 already")
+                    .at_line(3)
+            )
+        );
+        assert_eq!(
+            parse_input(&lines[..1]),
+            Err(AocError::unexpected_end("a line with `0`"))
+        );
+    }
+
+    #[test]
+    fn test_mix() {
+        // pseudo random numbers with plenty of duplicates and large offsets:
+        let mut state = 12345u64;
+        let input = (0..500)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 33) as i64 % 2000 - 1000
+            })
+            .map(|value| value * 811589153)
+            .collect_vec();
+        let mut numbers = input
+            .iter()
+            .map(|value| *value as i128)
+            .collect::<CircularList<_>>();
+        let mut reference = to_indexed_numbers(input);
+        for _round in 0..3 {
+            mix(&mut numbers);
+            for index in 0..reference.len() {
+                do_step(&mut reference, index);
+            }
+            assert_eq!(
+                numbers.iter().copied().collect_vec(),
+                reference
+                    .iter()
+                    .map(|(_, value)| *value as i128)
+                    .collect_vec()
+            );
+        }
+    }
+
+    #[test]
+    fn test_decrypt() {
+        let numbers = vec![1, 2, -3, 3, -2, 0, 4];
+        assert_eq!(decrypt(&numbers, &Decryption::part_one()), Ok(3));
+        assert_eq!(decrypt(&numbers, &Decryption::part_two()), Ok(1623178306));
+
+        // the coordinates are 4, -3 and 2 after one round:
+        let negative = Decryption {
+            offsets: vec![1000, 2000],
+            ..Decryption::part_one()
+        };
+        assert_eq!(decrypt(&numbers, &negative), Ok(1));
+        let negative = Decryption {
+            offsets: vec![2000],
+            ..Decryption::part_one()
+        };
+        assert_eq!(decrypt(&numbers, &negative), Ok(-3));
+
+        // only the key modulo `len - 1` affects the mixing, so this key picks the same numbers:
+        let key = 811589153 + 6 * (1 << 70);
+        let large = Decryption {
+            key,
+            ..Decryption::part_two()
+        };
+        assert_eq!(decrypt(&numbers, &large), Ok(2 * key));
+        let too_large = Decryption {
+            key: i128::MAX,
+            ..Decryption::part_one()
+        };
+        assert!(decrypt(&numbers, &too_large).is_err());
+    }
+}
@@ -0,0 +1,893 @@
+use crate::number_theory::ArithmeticError;
+use crate::rational::Rational;
+use crate::search::bfs;
+use crate::solution::Solution;
+use crate::AocError;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct Day21;
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Number(i64),
+    Monkey(String),
+}
+
+#[derive(Debug)]
+struct Operation {
+    operator: Option<Operator>,
+    first_operand: Operand,
+    second_operand: Option<Operand>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Operator {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Operator::Plus => '+',
+            Operator::Minus => '-',
+            Operator::Multiply => '*',
+            Operator::Divide => '/',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Number(number) => write!(f, "{}", number),
+            Operand::Monkey(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.first_operand)?;
+        if let (Some(operator), Some(second)) = (&self.operator, &self.second_operand) {
+            write!(f, " {} {}", operator, second)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_operator(input: &str) -> Option<Operator> {
+    match input.trim() {
+        "+" => Some(Operator::Plus),
+        "-" => Some(Operator::Minus),
+        "*" => Some(Operator::Multiply),
+        "/" => Some(Operator::Divide),
+        _ => None,
+    }
+}
+
+fn parse_operand(input: &str) -> Option<Operand> {
+    if let Ok(number) = input.parse::<i64>() {
+        return Some(Operand::Number(number));
+    }
+    if input.is_empty() {
+        return None;
+    }
+    Some(Operand::Monkey(input.to_string()))
+}
+
+fn parse_operation(input: &str) -> Result<Operation, AocError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^\s*(?P<first_operand>[a-z\d]+)\s*(?:(?P<operator>[+\-*/])\s*(?P<second_operand>[a-z]+))?\s*$"
+        )
+        .unwrap();
+    };
+    let error = || AocError::parse(input, "a number or `name <+|-|*|/> name`");
+    let captures = RE.captures(input).ok_or_else(error)?;
+    let first_operand = captures
+        .name("first_operand")
+        .and_then(|operand| parse_operand(operand.as_str()))
+        .ok_or_else(error)?;
+    let second_operand = parse_operand(
+        captures
+            .name("second_operand")
+            .map_or_else(|| "", |v| v.as_str()),
+    );
+    let operator = parse_operator(captures.name("operator").map_or_else(|| "", |v| v.as_str()));
+    Ok(Operation {
+        operator,
+        first_operand,
+        second_operand,
+    })
+}
+
+#[derive(Debug)]
+pub struct Monkey {
+    name: String,
+    operation: Operation,
+}
+
+fn parse_monkey(input: &str) -> Result<Monkey, AocError> {
+    let (name, operation) = input
+        .split_once(':')
+        .ok_or_else(|| AocError::parse(input, "`name: operation`"))?;
+    Ok(Monkey {
+        name: name.to_string(),
+        operation: parse_operation(operation).map_err(|err| err.at_column(name.len() + 1))?,
+    })
+}
+
+fn parse_monkeys(input: &[String]) -> Result<HashMap<String, Monkey>, AocError> {
+    let mut result = HashMap::new();
+    for (index, line) in input.iter().enumerate() {
+        let monkey = parse_monkey(line).map_err(|err| err.at_line(index))?;
+        if result.contains_key(&monkey.name) {
+            return Err(AocError::parse(line, "a unique monkey name").at_line(index));
+        }
+        result.insert(monkey.name.clone(), monkey);
+    }
+    Ok(result)
+}
+
+impl Operation {
+    /// The names of the monkeys this operation listens to.
+    fn dependencies(&self) -> impl Iterator<Item = &str> {
+        [Some(&self.first_operand), self.second_operand.as_ref()]
+            .into_iter()
+            .flatten()
+            .filter_map(|operand| match operand {
+                Operand::Monkey(name) => Some(name.as_str()),
+                Operand::Number(_) => None,
+            })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Mark {
+    Visiting,
+    Done,
+}
+
+/// Orders the `targets` and all monkeys they depend on so that every monkey comes after
+/// the monkeys it listens to.
+fn topological_order<'a>(
+    monkeys: &'a HashMap<String, Monkey>,
+    targets: &[&'a str],
+) -> Result<Vec<&'a str>, AocError> {
+    let mut marks = HashMap::new();
+    let mut order = Vec::new();
+    for &target in targets {
+        if !monkeys.contains_key(target) {
+            return Err(AocError::solve(format!(
+                "There is no monkey named `{}`.",
+                target
+            )));
+        }
+        if marks.contains_key(target) {
+            continue;
+        }
+        marks.insert(target, Mark::Visiting);
+        // a depth first search that remembers how many dependencies of each monkey on the
+        // stack have been visited already:
+        let mut stack = vec![(target, 0)];
+        while let Some(&(name, next)) = stack.last() {
+            let Some(dependency) = monkeys[name].operation.dependencies().nth(next) else {
+                marks.insert(name, Mark::Done);
+                order.push(name);
+                stack.pop();
+                continue;
+            };
+            stack.last_mut().expect("The stack isn't empty.").1 += 1;
+            match marks.get(dependency) {
+                Some(Mark::Done) => (),
+                Some(Mark::Visiting) => {
+                    let cycle = stack
+                        .iter()
+                        .map(|(name, _)| *name)
+                        .skip_while(|name| *name != dependency)
+                        .chain([dependency])
+                        .map(|name| format!("`{}`", name))
+                        .collect::<Vec<_>>();
+                    return Err(AocError::solve(format!(
+                        "The monkeys {} wait for each other.",
+                        cycle.join(" -> ")
+                    )));
+                }
+                None => {
+                    let (dependency, _) = monkeys.get_key_value(dependency).ok_or_else(|| {
+                        AocError::solve(format!(
+                            "Monkey `{}` listens to `{}`, but there is no such monkey.",
+                            name, dependency
+                        ))
+                    })?;
+                    marks.insert(dependency, Mark::Visiting);
+                    stack.push((dependency, 0));
+                }
+            }
+        }
+    }
+    Ok(order)
+}
+
+fn apply(name: &str, first: i64, operator: Operator, second: i64) -> Result<i64, AocError> {
+    let result = match operator {
+        Operator::Plus => first.checked_add(second),
+        Operator::Minus => first.checked_sub(second),
+        Operator::Multiply => first.checked_mul(second),
+        Operator::Divide if second == 0 => {
+            return Err(AocError::solve(format!(
+                "Monkey `{}` divides {} by zero.",
+                name, first
+            )))
+        }
+        Operator::Divide => first.checked_div(second),
+    };
+    result.ok_or_else(|| {
+        AocError::solve(format!(
+            "Monkey `{}` overflows computing {} {} {}.",
+            name, first, operator, second
+        ))
+    })
+}
+
+/// Computes what monkey `name` yells, given what the monkeys it listens to yell.
+fn yell<F>(name: &str, operation: &Operation, value_of: F) -> Result<i64, AocError>
+where
+    F: Fn(&str) -> Result<i64, AocError>,
+{
+    let value_of = |operand: &Operand| match operand {
+        Operand::Number(number) => Ok(*number),
+        Operand::Monkey(monkey) => value_of(monkey),
+    };
+    let first = value_of(&operation.first_operand)?;
+    match (operation.operator, &operation.second_operand) {
+        (Some(operator), Some(second)) => apply(name, first, operator, value_of(second)?),
+        _ => Ok(first),
+    }
+}
+
+/// Computes what the `targets` yell, evaluating every monkey they depend on exactly once.
+fn evaluate<'a>(
+    monkeys: &'a HashMap<String, Monkey>,
+    targets: &[&'a str],
+) -> Result<HashMap<&'a str, i64>, AocError> {
+    let mut values = HashMap::new();
+    for name in topological_order(monkeys, targets)? {
+        let value = yell(name, &monkeys[name].operation, |monkey| Ok(values[monkey]))?;
+        values.insert(name, value);
+    }
+    Ok(values)
+}
+
+fn do_monkey_math(monkeys: &HashMap<String, Monkey>, target: &str) -> Result<i64, AocError> {
+    Ok(evaluate(monkeys, &[target])?[target])
+}
+
+/// The monkeys from `from` to `to`, each listening to the next one.
+fn yell_chain<'a>(
+    monkeys: &'a HashMap<String, Monkey>,
+    from: &'a str,
+    to: &str,
+) -> Result<Vec<&'a str>, AocError> {
+    for name in [from, to] {
+        if !monkeys.contains_key(name) {
+            return Err(AocError::solve(format!(
+                "There is no monkey named `{}`.",
+                name
+            )));
+        }
+    }
+    let dependencies = |name: &&'a str| {
+        monkeys
+            .get(*name)
+            .into_iter()
+            .flat_map(|monkey| monkey.operation.dependencies())
+    };
+    bfs(from, dependencies, |name| *name == to)
+        .map(|path| path.nodes)
+        .ok_or_else(|| {
+            AocError::solve(format!(
+                "Monkey `{}` doesn't listen to `{}`, not even indirectly.",
+                from, to
+            ))
+        })
+}
+
+/// What to show in addition to the monkeys and who they listen to.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct DotOptions {
+    /// Adds the number every monkey yells to its label.
+    pub values: bool,
+    /// Highlights the yell chain from the first to the second monkey.
+    pub path: Option<(String, String)>,
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Renders the monkeys as a Graphviz graph with an edge from every monkey to each monkey
+/// it listens to.
+pub fn to_dot(monkeys: &HashMap<String, Monkey>, options: &DotOptions) -> Result<String, AocError> {
+    let names = monkeys.keys().map(String::as_str).sorted().collect_vec();
+    let values = match options.values {
+        true => Some(evaluate(monkeys, &names)?),
+        false => None,
+    };
+    let chain = match &options.path {
+        Some((from, to)) => yell_chain(monkeys, from, to)?,
+        None => Vec::new(),
+    };
+    let highlighted = " color=red penwidth=2";
+
+    let mut dot = String::from("digraph monkeys {\n");
+    for name in &names {
+        let mut label = vec![name.to_string(), monkeys[*name].operation.to_string()];
+        if let Some(values) = &values {
+            label.push(format!("= {}", values[name]));
+        }
+        let label = label.iter().map(|line| dot_escape(line)).join("\\n");
+        let style = match chain.contains(name) {
+            true => highlighted,
+            false => "",
+        };
+        dot.push_str(&format!(
+            "    \"{}\" [label=\"{}\"{}];\n",
+            dot_escape(name),
+            label,
+            style
+        ));
+    }
+    for name in &names {
+        for dependency in monkeys[*name].operation.dependencies() {
+            let is_on_chain = chain
+                .windows(2)
+                .any(|pair| pair[0] == *name && pair[1] == dependency);
+            let style = match is_on_chain {
+                true => format!(" [{}]", highlighted.trim_start()),
+                false => String::new(),
+            };
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\"{};\n",
+                dot_escape(name),
+                dot_escape(dependency),
+                style
+            ));
+        }
+    }
+    dot.push_str("}\n");
+    Ok(dot)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EquationError {
+    /// The unknown is multiplied with itself or divided by.
+    Nonlinear,
+    NoSolution,
+    NoIntegerSolution(Rational),
+    InfinitelyManySolutions,
+    Arithmetic(ArithmeticError),
+}
+
+impl fmt::Display for EquationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquationError::Nonlinear => write!(f, "the equation isn't linear"),
+            EquationError::NoSolution => write!(f, "the equation has no solution"),
+            EquationError::NoIntegerSolution(solution) => {
+                write!(f, "the only solution {} isn't an integer", solution)
+            }
+            EquationError::InfinitelyManySolutions => {
+                write!(f, "the equation holds for every value")
+            }
+            EquationError::Arithmetic(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<ArithmeticError> for EquationError {
+    fn from(err: ArithmeticError) -> Self {
+        EquationError::Arithmetic(err)
+    }
+}
+
+impl From<EquationError> for AocError {
+    fn from(err: EquationError) -> Self {
+        AocError::solve(format!("Couldn't solve for the unknown monkey: {}.", err))
+    }
+}
+
+/// `slope * x + intercept`, which every expression with at most linear terms of the
+/// unknown `x` simplifies to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Linear {
+    slope: Rational,
+    intercept: Rational,
+}
+
+impl Linear {
+    fn constant(value: Rational) -> Linear {
+        Linear {
+            slope: Rational::ZERO,
+            intercept: value,
+        }
+    }
+
+    fn is_constant(&self) -> bool {
+        self.slope.is_zero()
+    }
+
+    fn scale(&self, factor: Rational) -> Result<Linear, EquationError> {
+        Ok(Linear {
+            slope: self.slope.checked_mul(factor)?,
+            intercept: self.intercept.checked_mul(factor)?,
+        })
+    }
+
+    fn add(&self, other: &Linear) -> Result<Linear, EquationError> {
+        Ok(Linear {
+            slope: self.slope.checked_add(other.slope)?,
+            intercept: self.intercept.checked_add(other.intercept)?,
+        })
+    }
+}
+
+fn combine(left: Linear, operator: Operator, right: Linear) -> Result<Linear, EquationError> {
+    match operator {
+        Operator::Plus => left.add(&right),
+        Operator::Minus => left.add(&right.scale(Rational::integer(-1))?),
+        Operator::Multiply if left.is_constant() => right.scale(left.intercept),
+        Operator::Multiply if right.is_constant() => left.scale(right.intercept),
+        Operator::Divide if right.is_constant() => left.scale(right.intercept.reciprocal()?),
+        Operator::Multiply | Operator::Divide => Err(EquationError::Nonlinear),
+    }
+}
+
+/// Finds the number `unknown` has to yell for both operands of `root` to be equal.
+pub fn solve_for(monkeys: &HashMap<String, Monkey>, unknown: &str) -> Result<i64, AocError> {
+    if !monkeys.contains_key(unknown) {
+        return Err(AocError::solve(format!(
+            "There is no monkey named `{}`.",
+            unknown
+        )));
+    }
+    let order = topological_order(monkeys, &["root"])?;
+    if !order.contains(&unknown) {
+        return Err(AocError::solve(format!(
+            "Monkey `root` doesn't depend on `{}`.",
+            unknown
+        )));
+    }
+    let root = &monkeys["root"];
+    let Some(second_operand) = &root.operation.second_operand else {
+        return Err(AocError::solve(
+            "The `root` monkey doesn't compare two numbers.",
+        ));
+    };
+
+    // every monkey comes after the monkeys it listens to, so each one is simplified once.
+    // Errors are only raised for monkeys that `root` needs, which excludes the ones only
+    // the unknown monkey listens to:
+    let mut linears = HashMap::<&str, Result<Linear, EquationError>>::new();
+    let linear =
+        |linears: &HashMap<&str, Result<Linear, EquationError>>, operand: &Operand| match operand {
+            Operand::Number(number) => Ok(Linear::constant(Rational::from(*number))),
+            Operand::Monkey(name) => linears[name.as_str()],
+        };
+    for name in order {
+        let operation = &monkeys[name].operation;
+        let result = match (
+            name == unknown,
+            operation.operator,
+            &operation.second_operand,
+        ) {
+            (true, _, _) => Ok(Linear {
+                slope: Rational::ONE,
+                intercept: Rational::ZERO,
+            }),
+            (false, Some(operator), Some(second)) => linear(&linears, &operation.first_operand)
+                .and_then(|first| combine(first, operator, linear(&linears, second)?)),
+            (false, _, _) => linear(&linears, &operation.first_operand),
+        };
+        linears.insert(name, result);
+    }
+    let left = linear(&linears, &root.operation.first_operand)?;
+    let right = linear(&linears, second_operand)?;
+
+    // left.slope * x + left.intercept = right.slope * x + right.intercept:
+    let slope = left
+        .slope
+        .checked_sub(right.slope)
+        .map_err(EquationError::from)?;
+    let intercept = right
+        .intercept
+        .checked_sub(left.intercept)
+        .map_err(EquationError::from)?;
+    if slope.is_zero() {
+        return match intercept.is_zero() {
+            true => Err(EquationError::InfinitelyManySolutions.into()),
+            false => Err(EquationError::NoSolution.into()),
+        };
+    }
+    let solution = intercept.checked_div(slope).map_err(EquationError::from)?;
+    let integer = solution
+        .to_integer()
+        .ok_or(EquationError::NoIntegerSolution(solution))?;
+    i64::try_from(integer).map_err(|_| EquationError::Arithmetic(ArithmeticError::Overflow).into())
+}
+
+/// The monkeys of an input whose jobs can be queried and replaced one command at a time.
+pub struct WhatIf {
+    monkeys: HashMap<String, Monkey>,
+}
+
+impl WhatIf {
+    pub const HELP: &'static str = "<name>              shows what the monkey yells
+<name> = <job>      replaces the job of a monkey, e.g. `humn = 301` or `root = pppw - sjmn`";
+
+    pub fn new(monkeys: HashMap<String, Monkey>) -> WhatIf {
+        WhatIf { monkeys }
+    }
+
+    /// What every monkey yells, or why it can't. Only monkeys waiting for each other or
+    /// listening to a missing monkey fail as a whole, so that a single monkey that
+    /// overflows can still be replaced.
+    fn values(&self) -> Result<HashMap<String, Result<i64, AocError>>, AocError> {
+        let names = self
+            .monkeys
+            .keys()
+            .map(String::as_str)
+            .sorted()
+            .collect_vec();
+        let mut values = HashMap::<&str, Result<i64, AocError>>::new();
+        for name in topological_order(&self.monkeys, &names)? {
+            let value = yell(name, &self.monkeys[name].operation, |monkey| {
+                values[monkey].clone()
+            });
+            values.insert(name, value);
+        }
+        Ok(values
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect())
+    }
+
+    fn show(value: &Result<i64, AocError>) -> String {
+        match value {
+            Ok(value) => value.to_string(),
+            Err(err) => format!("error ({})", err),
+        }
+    }
+
+    /// Runs a single command and returns the text to show for it.
+    pub fn execute(&mut self, command: &str) -> Result<String, AocError> {
+        let Some((name, job)) = command.split_once('=') else {
+            let name = command.trim();
+            return Ok(format!(
+                "{} = {}",
+                name,
+                do_monkey_math(&self.monkeys, name)?
+            ));
+        };
+        let name = name.trim();
+        if !self.monkeys.contains_key(name) {
+            return Err(AocError::solve(format!(
+                "There is no monkey named `{}`.",
+                name
+            )));
+        }
+        let operation = parse_operation(job).map_err(|err| err.at_column(name.len() + 1))?;
+        let before = self.values()?;
+        let monkey = Monkey {
+            name: name.to_string(),
+            operation,
+        };
+        let previous = self.monkeys.insert(name.to_string(), monkey);
+        let after = match self.values() {
+            Ok(after) => after,
+            Err(err) => {
+                // keep the monkeys consistent if the new job doesn't work out:
+                self.monkeys
+                    .extend(previous.map(|monkey| (name.to_string(), monkey)));
+                return Err(err);
+            }
+        };
+
+        let mut lines = vec![format!("{} = {}", name, Self::show(&after[name]))];
+        let changed = after
+            .iter()
+            .filter(|(other, value)| other.as_str() != name && before[other.as_str()] != **value)
+            .sorted_by_key(|(other, _)| other.as_str())
+            .map(|(other, value)| {
+                let before = Self::show(&before[other.as_str()]);
+                format!("  {}: {} -> {}", other, before, Self::show(value))
+            })
+            .collect_vec();
+        match changed.is_empty() {
+            true => lines.push("No other monkey yells something else.".to_string()),
+            false => lines.extend(changed),
+        }
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, Monkey>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        parse_monkeys(lines)
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Result<i64, AocError> {
+        do_monkey_math(monkeys, "root")
+    }
+
+    fn part_two(monkeys: &Self::Input) -> Result<i64, AocError> {
+        solve_for(monkeys, "humn")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn monkeys(lines: &[&str]) -> HashMap<String, Monkey> {
+        let lines = lines
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        parse_monkeys(&lines).expect("Couldn't parse monkeys.")
+    }
+
+    #[test]
+    fn test_do_monkey_math() {
+        let lines = read_input("inputs/day21-example.txt").expect("Couldn't read example input.");
+        let example = parse_monkeys(&lines).expect("Couldn't parse example input.");
+        assert_eq!(do_monkey_math(&example, "root"), Ok(152));
+        assert_eq!(do_monkey_math(&example, "pppw"), Ok(2));
+
+        // a chain that is far too deep to evaluate recursively:
+        let mut chain = (0..100000)
+            .map(|index| format!("m{}: m{} + one", index, index + 1))
+            .collect::<Vec<_>>();
+        chain.extend(["m100000: 0".to_string(), "one: 1".to_string()]);
+        let chain = parse_monkeys(&chain).expect("Couldn't parse chain.");
+        assert_eq!(do_monkey_math(&chain, "m0"), Ok(100000));
+    }
+
+    #[test]
+    fn test_do_monkey_math_errors() {
+        let cycle = monkeys(&["root: a + b", "a: b * c", "b: 2", "c: root - b"]);
+        assert_eq!(
+            do_monkey_math(&cycle, "root"),
+            Err(AocError::solve(
+                "The monkeys `root` -> `a` -> `c` -> `root` wait for each other."
+            ))
+        );
+        let undefined = monkeys(&["root: a + b", "a: 2"]);
+        assert_eq!(
+            do_monkey_math(&undefined, "root"),
+            Err(AocError::solve(
+                "Monkey `root` listens to `b`, but there is no such monkey."
+            ))
+        );
+        assert_eq!(
+            do_monkey_math(&undefined, "humn"),
+            Err(AocError::solve("There is no monkey named `humn`."))
+        );
+        let overflow = monkeys(&["root: a * a", "a: 9223372036854775807"]);
+        assert_eq!(
+            do_monkey_math(&overflow, "root"),
+            Err(AocError::solve(
+                "Monkey `root` overflows computing 9223372036854775807 * 9223372036854775807."
+            ))
+        );
+        let division = monkeys(&["root: a / b", "a: 4", "b: a - a"]);
+        assert_eq!(
+            do_monkey_math(&division, "root"),
+            Err(AocError::solve("Monkey `root` divides 4 by zero."))
+        );
+    }
+
+    #[test]
+    fn test_yell_chain() {
+        let lines = read_input("inputs/day21-example.txt").expect("Couldn't read example input.");
+        let example = parse_monkeys(&lines).expect("Couldn't parse example input.");
+        assert_eq!(
+            yell_chain(&example, "root", "humn"),
+            Ok(vec!["root", "pppw", "cczh", "lgvd", "ptdq", "humn"])
+        );
+        assert_eq!(
+            yell_chain(&example, "humn", "root"),
+            Err(AocError::solve(
+                "Monkey `humn` doesn't listen to `root`, not even indirectly."
+            ))
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let small = monkeys(&["root: a + b", "a: 3", "b: a * c", "c: 2"]);
+        assert_eq!(
+            to_dot(&small, &DotOptions::default()),
+            Ok("digraph monkeys {
+    \"a\" [label=\"a\\n3\"];
+    \"b\" [label=\"b\\na * c\"];
+    \"c\" [label=\"c\\n2\"];
+    \"root\" [label=\"root\\na + b\"];
+    \"b\" -> \"a\";
+    \"b\" -> \"c\";
+    \"root\" -> \"a\";
+    \"root\" -> \"b\";
+}
+"
+            .to_string())
+        );
+        let options = DotOptions {
+            values: true,
+            path: Some(("root".to_string(), "c".to_string())),
+        };
+        let dot = to_dot(&small, &options).unwrap();
+        assert!(dot.contains("\"root\" [label=\"root\\na + b\\n= 9\" color=red penwidth=2];"));
+        assert!(dot.contains("\"a\" [label=\"a\\n3\\n= 3\"];"));
+        assert!(dot.contains("\"b\" -> \"c\" [color=red penwidth=2];"));
+        assert!(dot.contains("\"root\" -> \"a\";"));
+    }
+
+    #[test]
+    fn test_what_if() {
+        let lines = read_input("inputs/day21-example.txt").expect("Couldn't read example input.");
+        let mut what_if =
+            WhatIf::new(parse_monkeys(&lines).expect("Couldn't parse example input."));
+        assert_eq!(what_if.execute("root"), Ok("root = 152".to_string()));
+        assert_eq!(
+            what_if.execute("humn = 301"),
+            Ok("humn = 301
+  cczh: 8 -> 600
+  lgvd: 4 -> 596
+  pppw: 2 -> 150
+  ptdq: 2 -> 298
+  root: 152 -> 300"
+                .to_string())
+        );
+        assert_eq!(
+            what_if.execute("sllz = lfqf"),
+            Ok("sllz = 4\nNo other monkey yells something else.".to_string())
+        );
+        assert_eq!(what_if.execute("  pppw "), Ok("pppw = 150".to_string()));
+
+        assert_eq!(
+            what_if.execute("humn = root + dbpl"),
+            Err(AocError::solve(
+                "The monkeys `cczh` -> `lgvd` -> `ptdq` -> `humn` -> `root` -> `pppw` -> `cczh` wait for each other."
+            ))
+        );
+        assert_eq!(what_if.execute("humn"), Ok("humn = 301".to_string()));
+        assert_eq!(
+            what_if.execute("nobody = 3"),
+            Err(AocError::solve("There is no monkey named `nobody`."))
+        );
+        assert!(what_if.execute("humn = 3 +").is_err());
+    }
+
+    #[test]
+    fn test_what_if_failing_monkeys() {
+        let mut what_if = WhatIf::new(monkeys(&[
+            "root: a + b",
+            "a: c / d",
+            "b: 2",
+            "c: 6",
+            "d: 0",
+            "e: 3",
+        ]));
+        let division = "error (Monkey `a` divides 6 by zero.)";
+        // a failing monkey doesn't keep unrelated ones from being replaced:
+        assert_eq!(
+            what_if.execute("e = 4"),
+            Ok("e = 4\nNo other monkey yells something else.".to_string())
+        );
+        // monkeys listening to a failing one fail the same way:
+        assert_eq!(
+            what_if.execute("b = 3"),
+            Ok("b = 3\nNo other monkey yells something else.".to_string())
+        );
+        assert_eq!(
+            what_if.execute("d = 2"),
+            Ok(format!("d = 2\n  a: {0} -> 3\n  root: {0} -> 6", division))
+        );
+        assert_eq!(
+            what_if.execute("b = 9223372036854775807"),
+            Ok("b = 9223372036854775807
+  root: 6 -> error (Monkey `root` overflows computing 3 + 9223372036854775807.)"
+                .to_string())
+        );
+    }
+
+    #[test]
+    fn test_solve_for() {
+        let lines = read_input("inputs/day21-example.txt").expect("Couldn't read example input.");
+        let example = parse_monkeys(&lines).expect("Couldn't parse example input.");
+        assert_eq!(solve_for(&example, "humn"), Ok(301));
+        // with `humn` yelling 5, `sjmn` has to match the 2 that `pppw` yells:
+        assert_eq!(solve_for(&example, "sjmn"), Ok(2));
+
+        // the unknown may be used more than once:
+        let twice = monkeys(&["root: a + b", "a: humn + humn", "b: 6", "humn: 5"]);
+        assert_eq!(solve_for(&twice, "humn"), Ok(3));
+
+        // a chain that is far too deep to simplify recursively:
+        let mut chain = (0..100000)
+            .map(|index| format!("m{}: m{} + one", index, index + 1))
+            .collect::<Vec<_>>();
+        chain.extend([
+            "root: m0 + target".to_string(),
+            "m100000: humn".to_string(),
+            "humn: 0".to_string(),
+            "one: 1".to_string(),
+            "target: 0".to_string(),
+        ]);
+        let chain = parse_monkeys(&chain).expect("Couldn't parse chain.");
+        assert_eq!(solve_for(&chain, "humn"), Ok(-100000));
+
+        // shared operands that would double the work with every level if they were
+        // simplified again each time they are used:
+        let name = |level: usize| "s".repeat(level + 1);
+        let mut shared = (0..40)
+            .map(|level| format!("{}: {} + {}", name(level), name(level + 1), name(level + 1)))
+            .collect::<Vec<_>>();
+        shared.extend([
+            format!("root: {} - target", name(0)),
+            format!("{}: humn", name(40)),
+            "humn: 0".to_string(),
+            format!("target: {}", 3i64 << 40),
+        ]);
+        let shared = parse_monkeys(&shared).expect("Couldn't parse shared operands.");
+        assert_eq!(solve_for(&shared, "humn"), Ok(3));
+    }
+
+    #[test]
+    fn test_solve_for_errors() {
+        let square = monkeys(&["root: a + b", "a: humn * humn", "b: 4", "humn: 5"]);
+        assert_eq!(
+            solve_for(&square, "humn"),
+            Err(EquationError::Nonlinear.into())
+        );
+        let fraction = monkeys(&["root: a + b", "a: humn * c", "b: 7", "c: 2", "humn: 5"]);
+        assert_eq!(
+            solve_for(&fraction, "humn"),
+            Err(EquationError::NoIntegerSolution(Rational::new(7, 2).unwrap()).into())
+        );
+        let cancelled = monkeys(&["root: a + b", "a: humn - humn", "b: 1", "humn: 5"]);
+        assert_eq!(
+            solve_for(&cancelled, "humn"),
+            Err(EquationError::NoSolution.into())
+        );
+        let unrelated = monkeys(&["root: a + b", "a: 1", "b: 2", "humn: 5"]);
+        assert_eq!(
+            solve_for(&unrelated, "humn"),
+            Err(AocError::solve("Monkey `root` doesn't depend on `humn`."))
+        );
+        assert_eq!(
+            solve_for(&unrelated, "hmn"),
+            Err(AocError::solve("There is no monkey named `hmn`."))
+        );
+        let identity = monkeys(&["root: a + humn", "a: humn", "humn: 5"]);
+        assert_eq!(
+            solve_for(&identity, "humn"),
+            Err(EquationError::InfinitelyManySolutions.into())
+        );
+    }
+}
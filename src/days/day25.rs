@@ -0,0 +1,77 @@
+use crate::balanced::{Balanced, Quinary};
+use crate::solution::Solution;
+use crate::AocError;
+
+pub struct Day25;
+
+/// The balanced base five numbers the elves use for their fuel requirements.
+pub type Snafu = Balanced<Quinary>;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Vec<Snafu>;
+    type PartOne = Snafu;
+    type PartTwo = &'static str;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| line.parse().map_err(|err: AocError| err.at_line(index)))
+            .collect()
+    }
+
+    fn part_one(numbers: &Self::Input) -> Result<Snafu, AocError> {
+        Ok(numbers.iter().sum())
+    }
+
+    fn part_two(_numbers: &Self::Input) -> Result<&'static str, AocError> {
+        Ok("Merry Christmas!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number_theory::ArithmeticError;
+
+    fn snafu(input: &str) -> Snafu {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn test_conversions() {
+        let examples = [
+            (0, "0"),
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+            (-3, "-2"),
+            (-2022, "-2--1="),
+        ];
+        for (decimal, text) in examples {
+            assert_eq!(Snafu::from(decimal).to_string(), text);
+            assert_eq!(i64::try_from(&snafu(text)), Ok(decimal));
+        }
+        assert_eq!(snafu("0012").to_string(), "12");
+        assert_eq!(-snafu("1=11-2"), snafu("-2--1="));
+        assert_eq!(
+            Snafu::from(i128::MIN).to_string().parse(),
+            Ok(Snafu::from(i128::MIN))
+        );
+        assert_eq!(i128::try_from(&Snafu::from(i128::MAX)), Ok(i128::MAX));
+        assert_eq!(
+            i64::try_from(&Snafu::from(i64::MAX as i128 + 1)),
+            Err(ArithmeticError::Overflow)
+        );
+        assert!("".parse::<Snafu>().is_err());
+        assert_eq!(
+            "12a".parse::<Snafu>(),
+            Err(AocError::parse("12a", "one of `=`, `-`, `0`, `1` or `2`").at_column(2))
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::solution::Solution;
+use crate::AocError;
+use itertools::Itertools;
+
+pub struct Day3;
+
+#[derive(Debug)]
+struct Rucksack {
+    first_compartment: String,
+    second_compartment: String,
+}
+
+impl Rucksack {
+    fn from(input: &mut String) -> Rucksack {
+        let half_length = input.chars().count() / 2;
+        let first = input.drain(..half_length).collect::<String>();
+        Rucksack {
+            first_compartment: first,
+            second_compartment: input.clone(),
+        }
+    }
+
+    fn duplicate(self) -> Result<char, AocError> {
+        for item in self.first_compartment.chars() {
+            if self.second_compartment.contains(item) {
+                return Ok(item);
+            }
+        }
+        Err(AocError::solve("No duplicate found"))
+    }
+
+    fn str(&self) -> String {
+        self.first_compartment.clone() + &self.second_compartment
+    }
+}
+
+fn score(character: char) -> u32 {
+    let offset: i32 = if character.is_uppercase() { 27 } else { 1 }
+        - 'A'
+            .to_digit(36)
+            .expect("'A'.to_digit(36) failed unexpectedly") as i32;
+    let result = character
+        .to_digit(36)
+        .expect("couldn't calculate digit for given character") as i32
+        + offset;
+    assert!(result >= 0);
+    result as u32
+}
+
+fn group_badge(group: &[Rucksack]) -> Result<char, AocError> {
+    if group.len() != 3 {
+        return Err(AocError::solve(format!(
+            "Expected groups of three rucksacks, found one of {}",
+            group.len()
+        )));
+    }
+    let group_items = group.iter().map(Rucksack::str).collect::<String>();
+    let is_present_in_all_rucksacks =
+        |&item: &char| group.iter().all(|rucksack| rucksack.str().contains(item));
+    let mut uniq = group_items
+        .chars()
+        .filter(is_present_in_all_rucksacks)
+        .unique();
+    uniq.next()
+        .ok_or_else(|| AocError::solve("unable to find group badge"))
+}
+
+fn validate_rucksack(line: &str) -> Result<(), AocError> {
+    if let Some(column) = line.chars().position(|item| !item.is_ascii_alphabetic()) {
+        return Err(AocError::parse(line, "only letters").at_column(column));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(AocError::parse(line, "an even number of items"));
+    }
+    Ok(())
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        for (index, line) in lines.iter().enumerate() {
+            validate_rucksack(line).map_err(|err| err.at_line(index))?;
+        }
+        Ok(lines.to_vec())
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<u32, AocError> {
+        let mut lines = lines.clone();
+        let total_score = lines
+            .iter_mut()
+            .map(Rucksack::from)
+            .map(Rucksack::duplicate)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(score)
+            .sum();
+        Ok(total_score)
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<u32, AocError> {
+        let mut lines = lines.clone();
+        let score = lines
+            .iter_mut()
+            .map(Rucksack::from)
+            .collect::<Vec<Rucksack>>()
+            .chunks(3)
+            .map(group_badge)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(score)
+            .sum();
+        Ok(score)
+    }
+}
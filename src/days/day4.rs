@@ -0,0 +1,240 @@
+use crate::interval::Interval;
+use crate::solution::Solution;
+use crate::AocError;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day4;
+
+type Section = Interval<u32>;
+
+fn parse_section(input: &str) -> Result<Section, AocError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\d*)-(\d*)$").unwrap();
+    }
+    match RE.captures(input.trim()) {
+        Some(captures) => match (captures.get(1), captures.get(2)) {
+            (Some(first), Some(second)) => match (
+                first.as_str().parse::<u32>(),
+                second.as_str().parse::<u32>(),
+            ) {
+                (Ok(start), Ok(end)) => Interval::new(start, end)
+                    .ok_or_else(|| AocError::parse(input, "a section end not before its start")),
+                (Err(_), _) => Err(AocError::parse(input, "a section start")),
+                (_, Err(_)) => Err(AocError::parse(input, "a section end")),
+            },
+            (None, _) => Err(AocError::parse(input, "a section start")),
+            (_, None) => Err(AocError::parse(input, "a section end")),
+        },
+        None => Err(AocError::parse(input, "a section like `2-4`")),
+    }
+}
+
+fn parse_line(input: &str) -> Result<(Section, Section), AocError> {
+    let mut sections = input.split(',');
+    match (sections.next(), sections.next()) {
+        (Some(first), Some(second)) => Ok((
+            parse_section(first).map_err(|err| err.at_column(0))?,
+            parse_section(second).map_err(|err| err.at_column(first.len() + 1))?,
+        )),
+        (None, _) => Err(AocError::parse(input, "two comma separated sections")),
+        (_, None) => Err(AocError::parse(input, "two comma separated sections")),
+    }
+}
+
+/// Whether one section contains the other, including both of their end points, so that
+/// sections sharing an end like `6-6` and `4-6` count as well.
+fn range_fully_contains(first: Section, second: Section) -> bool {
+    first.contains_interval(&second) || second.contains_interval(&first)
+}
+
+fn ranges_overlap(first: Section, second: Section) -> bool {
+    first.overlaps(&second)
+}
+
+fn count_groups(groups: &[(Section, Section)], f: fn(Section, Section) -> bool) -> usize {
+    groups
+        .iter()
+        .filter(|(first, second)| f(*first, *second))
+        .count()
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Section, Section)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| parse_line(line).map_err(|err| err.at_line(index)))
+            .collect()
+    }
+
+    fn part_one(groups: &Self::Input) -> Result<usize, AocError> {
+        Ok(count_groups(groups, range_fully_contains))
+    }
+
+    fn part_two(groups: &Self::Input) -> Result<usize, AocError> {
+        Ok(count_groups(groups, ranges_overlap))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn section(start: u32, end: u32) -> Section {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn simple_section_parse() {
+        let r = parse_section("42-42");
+        assert_eq!(r, Ok(section(42, 42)));
+    }
+
+    #[test]
+    fn invalid_section_parse() {
+        assert!(parse_section("a-").is_err());
+        assert!(parse_section("4-2").is_err());
+    }
+
+    fn test_section_commutative(f: fn(Section, Section) -> bool, first: Section, second: Section) {
+        assert_eq!(
+            f(first, second),
+            f(second, first),
+            "f is expected to be commutative in its arguments"
+        );
+    }
+
+    fn test_full_overlap_commutative(first: Section, second: Section, expected: bool) {
+        assert_eq!(range_fully_contains(first, second), expected);
+        test_section_commutative(range_fully_contains, first, second)
+    }
+
+    #[test]
+    fn contains_no_overlap_right() {
+        /*
+          .234.....  2-4
+          .....678.  6-8
+        */
+        test_full_overlap_commutative(section(2, 4), section(6, 8), false)
+    }
+
+    #[test]
+    fn contains_partial_overlap_right() {
+        /*
+          ....567..  5-7
+          ......789  7-9
+        */
+        test_full_overlap_commutative(section(5, 7), section(7, 9), false)
+    }
+
+    #[test]
+    fn contains_fully_contained() {
+        /*
+          .2345678.  2-8
+          ..34567..  3-7
+        */
+        test_full_overlap_commutative(section(2, 8), section(3, 7), true)
+    }
+
+    #[test]
+    fn contains_fully_contained_at_end() {
+        /*
+          .....6...  6-6
+          ...456...  4-6
+        */
+        test_full_overlap_commutative(section(6, 6), section(4, 6), true)
+    }
+
+    #[test]
+    fn contains_fully_contained_at_start() {
+        /*
+          ..34567..  3-7
+          ..345....  3-5
+        */
+        test_full_overlap_commutative(section(3, 7), section(3, 5), true)
+    }
+
+    #[test]
+    fn contains_identical_sections() {
+        /*
+          ..34567..  3-7
+          ..34567..  3-7
+        */
+        test_full_overlap_commutative(section(3, 7), section(3, 7), true)
+    }
+
+    #[test]
+    fn contains_partial_overlap_left() {
+        /*
+         .....67..  6-7
+         ...456...  4-6
+        */
+        test_full_overlap_commutative(section(6, 7), section(4, 6), false)
+    }
+
+    #[test]
+    fn contains_no_overlap_left() {
+        /*
+          .....6...  6-6
+          ...45....  4-5
+        */
+        test_full_overlap_commutative(section(6, 6), section(4, 5), false)
+    }
+
+    fn test_overlap_commutative(first: Section, second: Section, expected: bool) {
+        assert_eq!(ranges_overlap(first, second), expected);
+        test_section_commutative(ranges_overlap, first, second)
+    }
+
+    #[test]
+    fn overlaps_no_overlap_right() {
+        /*
+          .234.....  2-4
+          .....678.  6-8
+        */
+        test_overlap_commutative(section(2, 4), section(6, 8), false)
+    }
+
+    #[test]
+    fn overlaps_partial_overlap_right() {
+        /*
+          ....567..  5-7
+          ......789  7-9
+        */
+        test_overlap_commutative(section(5, 7), section(7, 9), true)
+    }
+
+    #[test]
+    fn overlaps_fully_contained() {
+        /*
+          .2345678.  2-8
+          ..34567..  3-7
+        */
+        test_overlap_commutative(section(2, 8), section(3, 7), true)
+    }
+
+    #[test]
+    fn overlaps_partial_overlap_left() {
+        /*
+         .....67..  6-7
+         ...456...  4-6
+        */
+        test_overlap_commutative(section(6, 7), section(4, 6), true)
+    }
+
+    #[test]
+    fn overlaps_no_overlap_left() {
+        /*
+          .....6...  6-6
+          ...45....  4-5
+        */
+        test_overlap_commutative(section(6, 6), section(4, 5), false)
+    }
+}
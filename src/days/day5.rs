@@ -1,8 +1,11 @@
+use crate::input::{header_and_body, Record};
+use crate::solution::Solution;
+use crate::AocError;
 use itertools::enumerate;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::slice::Iter;
-use utilities::*;
+
+pub struct Day5;
 
 fn parse_stack_line(input: &str) -> Vec<char> {
     lazy_static! {
@@ -11,7 +14,7 @@ fn parse_stack_line(input: &str) -> Vec<char> {
     let mut columns: Vec<char> = Vec::new();
     for captures in RE.captures_iter(input) {
         match captures.name("crate") {
-            Some(group) => match group.as_str().chars().nth(0) {
+            Some(group) => match group.as_str().chars().next() {
                 Some(crate_name) => columns.push(crate_name),
                 None => continue,
             },
@@ -28,8 +31,8 @@ fn is_stack_name_line(input: &str) -> bool {
     RE.is_match(input)
 }
 
-#[derive(PartialEq, Debug)]
-struct MoveInstruction {
+#[derive(PartialEq, Debug, Clone)]
+pub struct MoveInstruction {
     amount: usize,
     from: usize,
     to: usize,
@@ -37,55 +40,46 @@ struct MoveInstruction {
 
 type MoveInstructions = Vec<MoveInstruction>;
 
-fn parse_move_instruction(input: &str) -> Option<MoveInstruction> {
+fn parse_move_instruction(input: &str) -> Result<MoveInstruction, AocError> {
     lazy_static! {
         static ref RE: Regex =
             Regex::new(r"^move\s(?P<amount>\d+)\sfrom\s(?P<from>\d+)\sto\s(?P<to>\d+)").unwrap();
     }
-    match RE.captures(input) {
-        Some(captures) => {
-            match (
-                captures.name("from"),
-                captures.name("to"),
-                captures.name("amount"),
-            ) {
-                (Some(from), Some(to), Some(amount)) => match (
-                    from.as_str().parse::<usize>(),
-                    to.as_str().parse::<usize>(),
-                    amount.as_str().parse::<usize>(),
-                ) {
-                    (Ok(from), Ok(to), Ok(amount)) => Some(MoveInstruction {
-                        from: from,
-                        to: to,
-                        amount: amount,
-                    }),
-                    _ => None,
-                },
-                _ => None,
-            }
-        }
-        None => None,
+    let error = || AocError::parse(input, "`move N from N to N`");
+    let captures = RE.captures(input).ok_or_else(error)?;
+    let number = |name: &str| -> Result<usize, AocError> {
+        let capture = captures.name(name).ok_or_else(error)?;
+        capture
+            .as_str()
+            .parse::<usize>()
+            .map_err(|_| AocError::parse(input, "a number").at_column(capture.start()))
+    };
+    let (amount, from, to) = (number("amount")?, number("from")?, number("to")?);
+    if from == 0 || to == 0 {
+        return Err(AocError::parse(input, "stack numbers starting at 1"));
     }
+    Ok(MoveInstruction { amount, from, to })
 }
 
-fn parse_move_instructions(input: Iter<String>) -> Option<Vec<MoveInstruction>> {
+fn parse_move_instructions(input: Record) -> Result<Vec<MoveInstruction>, AocError> {
     let mut result = MoveInstructions::new();
-    for line in input {
-        match parse_move_instruction(line) {
-            Some(instruction) => result.push(instruction),
-            None => return None,
-        };
+    for (index, line) in input.enumerate() {
+        let instruction = parse_move_instruction(line).map_err(|err| err.at_line(index))?;
+        result.push(instruction);
     }
-    Some(result)
+    Ok(result)
 }
 
 type State = Vec<Vec<char>>;
 
-fn parse_state(mut input: Iter<String>) -> (Iter<String>, Option<State>) {
+fn parse_state(input: &[String]) -> Result<State, AocError> {
     let mut state = State::new();
-    while let Some(line) = input.next() {
+    for (line_index, line) in input.iter().enumerate() {
         if is_stack_name_line(line) {
-            return (input, Some(state));
+            return match input.get(line_index + 1) {
+                Some(line) => Err(AocError::parse(line, "an empty line").at_line(line_index + 1)),
+                None => Ok(state),
+            };
         }
         let new_items = parse_stack_line(line);
         if state.len() < new_items.len() {
@@ -97,23 +91,27 @@ fn parse_state(mut input: Iter<String>) -> (Iter<String>, Option<State>) {
             }
             match state.get_mut(index) {
                 Some(stack) => stack.insert(0, new_item),
-                None => return (input, None),
+                None => return Err(AocError::parse(line, "a line of crates").at_line(line_index)),
             }
         }
     }
-    (input, None)
+    Err(AocError::unexpected_end("a line of stack numbers"))
 }
 
-fn apply_instruction(mut input: State, instruction: MoveInstruction, do_reverse : bool) -> Result<State, String> {
+fn apply_instruction(
+    mut input: State,
+    instruction: MoveInstruction,
+    do_reverse: bool,
+) -> Result<State, AocError> {
     let mut crates;
     match input.get_mut(instruction.from - 1) {
         Some(from_state) => {
             crates = from_state.split_off(from_state.len().saturating_sub(instruction.amount));
             if do_reverse {
-              crates.reverse();
+                crates.reverse();
             }
         }
-        _ => return Err("Can't apply instruction to this state.".to_string()),
+        _ => return Err(AocError::solve("Can't apply instruction to this state.")),
     }
 
     match input.get_mut(instruction.to - 1) {
@@ -121,61 +119,38 @@ fn apply_instruction(mut input: State, instruction: MoveInstruction, do_reverse
             to_state.append(&mut crates);
             Ok(input)
         }
-        _ => Err("Can't apply instruction to this state.".to_string()),
+        _ => Err(AocError::solve("Can't apply instruction to this state.")),
     }
 }
 
-fn main() {
-    match read_input("inputs/day5.txt") {
-        Ok(lines) => {
-            let (mut iter, state) = parse_state(lines.iter());
-            iter.next(); // skip empty line
-            match state {
-                Some(mut state) => match parse_move_instructions(iter) {
-                    Some(instructions) => {
-                        for instruction in instructions {
-                            match apply_instruction(state, instruction, true) {
-                                Ok(new_state) => state = new_state,
-                                Err(msg) => panic!("Couldn't apply state to state: {}", msg),
-                            }
-                        }
-                        let mut solution = String::new();
-                        for stack in state {
-                            if !stack.is_empty() {
-                                solution.push(*stack.last().unwrap());
-                            }
-                        }
-                        println!("My solution would be {}.", solution);
-                    }
-                    None => println!("Couldn't parse move instructions."),
-                },
-                None => println!("Couldn't parse state."),
-            }
-            let (mut iter, state) = parse_state(lines.iter());
-            iter.next(); // skip empty line
-            match state {
-                Some(mut state) => match parse_move_instructions(iter) {
-                    Some(instructions) => {
-                        for instruction in instructions {
-                            match apply_instruction(state, instruction, false) {
-                                Ok(new_state) => state = new_state,
-                                Err(msg) => panic!("Couldn't apply state to state: {}", msg),
-                            }
-                        }
-                        let mut solution = String::new();
-                        for stack in state {
-                            if !stack.is_empty() {
-                                solution.push(*stack.last().unwrap());
-                            }
-                        }
-                        println!("My solution for part 2 would be {}.", solution);
-                    }
-                    None => println!("Couldn't parse move instructions."),
-                },
-                None => println!("Couldn't parse state."),
-            }
-        }
-        Err(err) => println!("Unable to read input: {}", err),
+fn rearrange(input: &(State, MoveInstructions), do_reverse: bool) -> Result<String, AocError> {
+    let (mut state, instructions) = input.clone();
+    for instruction in instructions {
+        state = apply_instruction(state, instruction, do_reverse)?;
+    }
+    Ok(state.iter().filter_map(|stack| stack.last()).collect())
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (State, MoveInstructions);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        let (drawing, moves) = header_and_body(lines);
+        let state = parse_state(drawing.lines)?;
+        let instructions = parse_move_instructions(moves)?;
+        Ok((state, instructions))
+    }
+
+    fn part_one(input: &Self::Input) -> Result<String, AocError> {
+        rearrange(input, true)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<String, AocError> {
+        rearrange(input, false)
     }
 }
 
@@ -209,23 +184,17 @@ pub mod test {
 
     #[test]
     fn test_is_stack_name_line() {
-        assert_eq!(
-            is_stack_name_line(" 1   2   3   4   5   6   7   8   9 "),
-            true
-        );
-        assert_eq!(
-            is_stack_name_line("[S] [S] [B] [D] [F] [L] [Z] [N] [L]"),
-            false
-        );
-        assert_eq!(is_stack_name_line(""), false);
-        assert_eq!(is_stack_name_line("move 4 from 2 to 1"), false);
+        assert!(is_stack_name_line(" 1   2   3   4   5   6   7   8   9 "));
+        assert!(!is_stack_name_line("[S] [S] [B] [D] [F] [L] [Z] [N] [L]"));
+        assert!(!is_stack_name_line(""));
+        assert!(!is_stack_name_line("move 4 from 2 to 1"));
     }
 
     #[test]
     fn test_parse_move_instruction() {
         assert_eq!(
             parse_move_instruction("move 15 from 6 to 4"),
-            Some(MoveInstruction {
+            Ok(MoveInstruction {
                 amount: 15,
                 from: 6,
                 to: 4
@@ -233,12 +202,14 @@ pub mod test {
         );
         assert_eq!(
             parse_move_instruction("move 7 from 5 to 6"),
-            Some(MoveInstruction {
+            Ok(MoveInstruction {
                 amount: 7,
                 from: 5,
                 to: 6
             })
         );
+        assert!(parse_move_instruction("move 7 from 0 to 6").is_err());
+        assert!(parse_move_instruction("move seven from 5 to 6").is_err());
     }
 
     #[test]
@@ -249,8 +220,8 @@ pub mod test {
             " 1   2 ".to_string(),
         ]
         .to_vec();
-        let (_, state) = parse_state(input.iter());
-        assert!(state.is_some());
+        let state = parse_state(&input);
+        assert!(state.is_ok());
         assert_eq!(
             state.unwrap(),
             [['S', 'T'].to_vec(), ['A'].to_vec()].to_vec()
@@ -261,8 +232,8 @@ pub mod test {
             " 1   2 ".to_string(),
         ]
         .to_vec();
-        let (_, state) = parse_state(input.iter());
-        assert!(state.is_some());
+        let state = parse_state(&input);
+        assert!(state.is_ok());
         assert_eq!(
             state.unwrap(),
             [['S'].to_vec(), ['A', 'T'].to_vec()].to_vec()
@@ -273,8 +244,8 @@ pub mod test {
             " 1   2   3 ".to_string(),
         ]
         .to_vec();
-        let (_, state) = parse_state(input.iter());
-        assert!(state.is_some());
+        let state = parse_state(&input);
+        assert!(state.is_ok());
         assert_eq!(
             state.unwrap(),
             [['S'].to_vec(), ['A', 'T'].to_vec(), ['B'].to_vec()].to_vec()
@@ -1,5 +1,8 @@
+use crate::solution::Solution;
+use crate::AocError;
 use itertools::Itertools;
-use utilities::*;
+
+pub struct Day6;
 
 fn contains_no_duplicates<T>(input: &[T]) -> bool
 where
@@ -8,7 +11,7 @@ where
     !(1..input.len()).any(|i| input[i..].contains(&input[i - 1]))
 }
 
-fn find_packet_marker(input: &String, length: usize) -> Result<usize, String> {
+fn find_packet_marker(input: &str, length: usize) -> Result<usize, AocError> {
     match input
         .chars()
         .collect::<Vec<char>>()
@@ -16,7 +19,7 @@ fn find_packet_marker(input: &String, length: usize) -> Result<usize, String> {
         .find_position(|window| contains_no_duplicates(window))
     {
         Some((index, _)) => Ok(index + length),
-        None => Err("Couldn't find start-of-packet marker".to_string()),
+        None => Err(AocError::solve("Couldn't find start-of-packet marker")),
     }
 }
 
@@ -25,24 +28,27 @@ enum MarkerType {
     MessageStart = 14,
 }
 
-fn scan_for_marker(input: &String, marker_type: MarkerType) {
-    match find_packet_marker(&input, marker_type as usize) {
-        Ok(marker_index) => println!("Found marker at {}", marker_index),
-        Err(msg) => println!("Couldn't find marker: {}", msg),
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        match lines {
+            [line] => Ok(line.clone()),
+            [] => Err(AocError::unexpected_end("one line of input")),
+            [_, line, ..] => Err(AocError::parse(line, "exactly one line of input").at_line(1)),
+        }
     }
-}
 
-fn main() {
-    match read_input("inputs/day6.txt") {
-        Ok(lines) => match lines.get(0) {
-            Some(line) => {
-                scan_for_marker(&line.clone(), MarkerType::PacketStart);
-                scan_for_marker(&line, MarkerType::MessageStart);
-            }
+    fn part_one(line: &Self::Input) -> Result<usize, AocError> {
+        find_packet_marker(line, MarkerType::PacketStart as usize)
+    }
 
-            None => println!("Expecting one line of input. Got none."),
-        },
-        Err(msg) => println!("Couldn't read input: {}", msg),
+    fn part_two(line: &Self::Input) -> Result<usize, AocError> {
+        find_packet_marker(line, MarkerType::MessageStart as usize)
     }
 }
 
@@ -52,75 +58,75 @@ pub mod test {
 
     #[test]
     fn test_containts_no_duplicates() {
-        assert_eq!(contains_no_duplicates(&[0, 1]), true);
-        assert_eq!(contains_no_duplicates(&[0, 0]), false);
-        assert_eq!(contains_no_duplicates(&['m', 'j', 'q', 'j']), false);
-        assert_eq!(contains_no_duplicates(&['j', 'q', 'j', 'p']), false);
-        assert_eq!(contains_no_duplicates(&['q', 'j', 'p', 'q']), false);
-        assert_eq!(contains_no_duplicates(&['j', 'p', 'q', 'm']), true);
+        assert!(contains_no_duplicates(&[0, 1]));
+        assert!(!contains_no_duplicates(&[0, 0]));
+        assert!(!contains_no_duplicates(&['m', 'j', 'q', 'j']));
+        assert!(!contains_no_duplicates(&['j', 'q', 'j', 'p']));
+        assert!(!contains_no_duplicates(&['q', 'j', 'p', 'q']));
+        assert!(contains_no_duplicates(&['j', 'p', 'q', 'm']));
     }
 
     #[test]
     fn test_find_packet_marker() {
         assert_eq!(
             find_packet_marker(
-                &"bvwbjplbgvbhsrlpgdmjqwftvncz".to_string(),
+                "bvwbjplbgvbhsrlpgdmjqwftvncz",
                 MarkerType::PacketStart as usize
             ),
             Ok(5)
         );
         assert_eq!(
             find_packet_marker(
-                &"nppdvjthqldpwncqszvftbrmjlhg".to_string(),
+                "nppdvjthqldpwncqszvftbrmjlhg",
                 MarkerType::PacketStart as usize
             ),
             Ok(6)
         );
         assert_eq!(
             find_packet_marker(
-                &"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string(),
+                "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
                 MarkerType::PacketStart as usize
             ),
             Ok(10)
         );
         assert_eq!(
             find_packet_marker(
-                &"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string(),
+                "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
                 MarkerType::PacketStart as usize
             ),
             Ok(11)
         );
         assert_eq!(
             find_packet_marker(
-                &"mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string(),
+                "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
                 MarkerType::MessageStart as usize
             ),
             Ok(19)
         );
         assert_eq!(
             find_packet_marker(
-                &"bvwbjplbgvbhsrlpgdmjqwftvncz".to_string(),
+                "bvwbjplbgvbhsrlpgdmjqwftvncz",
                 MarkerType::MessageStart as usize
             ),
             Ok(23)
         );
         assert_eq!(
             find_packet_marker(
-                &"nppdvjthqldpwncqszvftbrmjlhg".to_string(),
+                "nppdvjthqldpwncqszvftbrmjlhg",
                 MarkerType::MessageStart as usize
             ),
             Ok(23)
         );
         assert_eq!(
             find_packet_marker(
-                &"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string(),
+                "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
                 MarkerType::MessageStart as usize
             ),
             Ok(29)
         );
         assert_eq!(
             find_packet_marker(
-                &"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string(),
+                "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
                 MarkerType::MessageStart as usize
             ),
             Ok(26)
@@ -1,9 +1,12 @@
+use crate::solution::Solution;
+use crate::AocError;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
 use std::slice::Iter;
-use utilities::*;
+
+pub struct Day7;
 
 #[derive(PartialEq, Debug)]
 struct Command {
@@ -11,7 +14,7 @@ struct Command {
     argument: Option<String>,
 }
 
-fn parse_command_line(input: &String) -> Option<Command> {
+fn parse_command_line(input: &str) -> Option<Command> {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"\$\s(?P<command>cd|ls)\s?(?P<argument>.*)?").unwrap();
     }
@@ -23,7 +26,7 @@ fn parse_command_line(input: &String) -> Option<Command> {
     })
 }
 
-fn parse_dir_line(input: &String) -> Option<String> {
+fn parse_dir_line(input: &str) -> Option<String> {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"(dir)\s(?P<name>.*)").unwrap();
     }
@@ -38,7 +41,7 @@ struct File {
     size: usize,
 }
 
-fn parse_file_line(input: &String) -> Option<File> {
+fn parse_file_line(input: &str) -> Option<File> {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"(?P<size>\d*)\s(?P<name>.*)").unwrap();
     }
@@ -56,49 +59,53 @@ fn parse_file_line(input: &String) -> Option<File> {
 
 fn append_path(input: &mut String, item: String) {
     if input != "/" {
-        input.push_str("/");
+        input.push('/');
     }
     input.push_str(item.as_str());
 }
 
-fn parse_directory_tree(mut iter: Iter<String>) -> HashMap<String, usize> {
+fn parse_directory_tree(iter: Iter<String>) -> Result<HashMap<String, usize>, AocError> {
     let mut directory_sizes: HashMap<String, usize> = HashMap::new();
     directory_sizes.entry("/".to_string()).or_default();
     let mut path: Vec<String> = Vec::new();
 
-    while let Some(line) = iter.next() {
-        if let Some(command) = parse_command_line(&line.clone()) {
+    for (index, line) in iter.enumerate() {
+        if let Some(command) = parse_command_line(line) {
             match command.command.as_str() {
                 "cd" => {
                     if let Some(argument) = command.argument {
                         match argument.as_str() {
                             ".." => match path.pop() {
                                 Some(_) => (),
-                                None => panic!("There is not parent directory for '/'!"),
+                                None => {
+                                    return Err(
+                                        AocError::parse(line, "a parent directory").at_line(index)
+                                    )
+                                }
                             },
                             "/" => (),
                             _ => path.push(argument),
                         };
                     } else {
-                        panic!("cd needs an argument!");
+                        return Err(AocError::parse(line, "an argument to cd").at_line(index));
                     }
                 }
                 "ls" => (),
-                _ => panic!("Unknown command: {}", command.command),
+                _ => return Err(AocError::parse(line, "`cd` or `ls`").at_line(index)),
             };
-        }
-
-        if let Some(directory) = parse_dir_line(&line.clone()) {
+        } else if let Some(directory) = parse_dir_line(line) {
             let mut path = path.join("/");
             path.insert(0, '/');
             append_path(&mut path, directory);
             directory_sizes.entry(path).or_default();
-        }
-
-        if let Some(file) = parse_file_line(line) {
+        } else if let Some(file) = parse_file_line(line) {
             let mut path = path.join("/");
             path.insert(0, '/');
             *directory_sizes.entry(path.clone()).or_insert(0) += file.size;
+        } else {
+            return Err(
+                AocError::parse(line, "a command, a directory or a file listing").at_line(index),
+            );
         }
     }
 
@@ -111,38 +118,38 @@ fn parse_directory_tree(mut iter: Iter<String>) -> HashMap<String, usize> {
             }
         }
     }
-    accumulated_sizes
+    Ok(accumulated_sizes)
 }
 
-fn main() {
-    if let Ok(input) = read_input("inputs/day7.txt") {
-        let directory_sizes = parse_directory_tree(input.iter());
-        let accumulated_directory_size =
-            directory_sizes
-                .iter()
-                .fold(0 as usize, |accumulator, element| {
-                    if element.1 <= &100000 {
-                        accumulator + element.1
-                    } else {
-                        accumulator
-                    }
-                });
-        println!(
-            "All directories' sizes lower than 100000 sum up to = {}",
-            accumulated_directory_size
-        );
+impl Solution for Day7 {
+    const DAY: u8 = 7;
 
-        let required_space = directory_sizes.get("/").unwrap() - (70000000 - 30000000);
-        match directory_sizes
+    type Input = HashMap<String, usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        parse_directory_tree(lines.iter())
+    }
+
+    fn part_one(directory_sizes: &Self::Input) -> Result<usize, AocError> {
+        Ok(directory_sizes
+            .values()
+            .filter(|size| **size <= 100000)
+            .sum())
+    }
+
+    fn part_two(directory_sizes: &Self::Input) -> Result<usize, AocError> {
+        let used_space = directory_sizes
+            .get("/")
+            .ok_or_else(|| AocError::solve("Missing root directory!"))?;
+        let required_space = used_space.saturating_sub(70000000 - 30000000);
+        directory_sizes
             .values()
             .sorted()
-            .find(|element| element >= &&required_space)
-        {
-            Some(size) => println!("The size of the to be deleted directory is {}", size),
-            None => println!("Unable to find directory to delete!"),
-        }
-    } else {
-        println!("Unable to read input.");
+            .find(|size| **size >= required_space)
+            .copied()
+            .ok_or_else(|| AocError::solve("Unable to find directory to delete!"))
     }
 }
 
@@ -153,67 +160,64 @@ pub mod test {
     #[test]
     fn test_parse_command_line() {
         assert_eq!(
-            parse_command_line(&"$ cd /".to_string()),
+            parse_command_line("$ cd /"),
             Some(Command {
                 command: "cd".to_string(),
                 argument: Some("/".to_string())
             })
         );
         assert_eq!(
-            parse_command_line(&"$ ls".to_string()),
+            parse_command_line("$ ls"),
             Some(Command {
                 command: "ls".to_string(),
                 argument: Some("".to_string())
             })
         );
         assert_eq!(
-            parse_command_line(&"$ cd a".to_string()),
+            parse_command_line("$ cd a"),
             Some(Command {
                 command: "cd".to_string(),
                 argument: Some("a".to_string())
             })
         );
         assert_eq!(
-            parse_command_line(&"$ cd a123bz".to_string()),
+            parse_command_line("$ cd a123bz"),
             Some(Command {
                 command: "cd".to_string(),
                 argument: Some("a123bz".to_string())
             })
         );
         assert_eq!(
-            parse_command_line(&"$ cd ..".to_string()),
+            parse_command_line("$ cd .."),
             Some(Command {
                 command: "cd".to_string(),
                 argument: Some("..".to_string())
             })
         );
-        assert_eq!(parse_command_line(&"dir a123bz".to_string()), None);
-        assert_eq!(parse_command_line(&"29116 f".to_string()), None);
+        assert_eq!(parse_command_line("dir a123bz"), None);
+        assert_eq!(parse_command_line("29116 f"), None);
     }
 
     #[test]
     fn parse_parse_dir_line() {
-        assert_eq!(parse_dir_line(&"$ cd a123bz".to_string()), None);
-        assert_eq!(
-            parse_dir_line(&"dir a123bz".to_string()),
-            Some("a123bz".to_string())
-        );
-        assert_eq!(parse_dir_line(&"29116 f".to_string()), None);
+        assert_eq!(parse_dir_line("$ cd a123bz"), None);
+        assert_eq!(parse_dir_line("dir a123bz"), Some("a123bz".to_string()));
+        assert_eq!(parse_dir_line("29116 f"), None);
     }
 
     #[test]
     fn parse_parse_file_line() {
-        assert_eq!(parse_file_line(&"$ cd a123bz".to_string()), None);
-        assert_eq!(parse_file_line(&"dir a123bz".to_string()), None);
+        assert_eq!(parse_file_line("$ cd a123bz"), None);
+        assert_eq!(parse_file_line("dir a123bz"), None);
         assert_eq!(
-            parse_file_line(&"29116 f".to_string()),
+            parse_file_line("29116 f"),
             Some(File {
                 name: "f".to_string(),
                 size: 29116
             })
         );
         assert_eq!(
-            parse_file_line(&"5626152 d.ext".to_string()),
+            parse_file_line("5626152 d.ext"),
             Some(File {
                 name: "d.ext".to_string(),
                 size: 5626152
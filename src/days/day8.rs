@@ -0,0 +1,148 @@
+use crate::grid::{Grid, Point, DIRECTIONS_4};
+use crate::solution::Solution;
+use crate::AocError;
+
+pub struct Day8;
+
+type Value = usize;
+
+fn parse_grid(lines: &[String]) -> Result<Grid<Value>, AocError> {
+    Grid::parse(lines, "a digit", |c| {
+        c.to_digit(10).map(|digit| digit as Value)
+    })
+}
+
+fn is_visible(grid: &Grid<Value>, point: Point) -> bool {
+    let height = grid[point];
+    DIRECTIONS_4
+        .iter()
+        .any(|direction| grid.ray(point, *direction).all(|tree| *tree < height))
+}
+
+fn scenic_score(grid: &Grid<Value>, point: Point) -> usize {
+    DIRECTIONS_4
+        .iter()
+        .map(|direction| visible_trees_in_line(grid.ray(point, *direction), grid[point]))
+        .product()
+}
+
+fn maximum_scenic_score(grid: &Grid<Value>) -> usize {
+    grid.points()
+        .map(|point| scenic_score(grid, point))
+        .max()
+        .unwrap_or(0)
+}
+
+fn count_visible(grid: &Grid<Value>) -> Option<usize> {
+    if grid.is_empty() {
+        return None;
+    }
+    Some(
+        grid.points()
+            .filter(|point| is_visible(grid, *point))
+            .count(),
+    )
+}
+
+fn visible_trees_in_line<'a, T>(mut iter: T, reference_height: usize) -> usize
+where
+    T: Iterator<Item = &'a usize> + Clone,
+{
+    let count = iter.clone().count();
+    iter.position(|h| h >= &reference_height)
+        .map(|position| position + 1)
+        .unwrap_or(count)
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<Value>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        parse_grid(lines)
+    }
+
+    fn part_one(grid: &Self::Input) -> Result<usize, AocError> {
+        count_visible(grid).ok_or_else(|| AocError::solve("The grid is empty."))
+    }
+
+    fn part_two(grid: &Self::Input) -> Result<usize, AocError> {
+        Ok(maximum_scenic_score(grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{DOWN, LEFT, RIGHT, UP};
+
+    fn lines(input: &[&str]) -> Vec<String> {
+        input.iter().map(<&str>::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = parse_grid(&lines(&["01", "23"])).unwrap();
+        assert_eq!(grid, Grid::from_rows(vec![vec![0, 1], vec![2, 3]]).unwrap());
+        assert!(parse_grid(&lines(&["35x90"])).is_err());
+        assert!(parse_grid(&lines(&["01", "2"])).is_err());
+    }
+
+    fn example_grid() -> Grid<Value> {
+        parse_grid(&lines(&["30373", "25512", "65332", "33549", "35390"])).unwrap()
+    }
+
+    #[test]
+    fn test_is_visible() {
+        let grid = example_grid();
+
+        for i in 0..4 {
+            assert!(is_visible(&grid, Point::new(i, 0)));
+            assert!(is_visible(&grid, Point::new(i, 4)));
+            assert!(is_visible(&grid, Point::new(0, i)));
+            assert!(is_visible(&grid, Point::new(4, i)));
+        }
+
+        assert!(is_visible(&grid, Point::new(1, 1)));
+        assert!(is_visible(&grid, Point::new(1, 2)));
+        assert!(!is_visible(&grid, Point::new(1, 3)));
+        assert!(is_visible(&grid, Point::new(2, 1)));
+        assert!(!is_visible(&grid, Point::new(2, 2)));
+        assert!(is_visible(&grid, Point::new(2, 3)));
+        assert!(!is_visible(&grid, Point::new(3, 1)));
+        assert!(is_visible(&grid, Point::new(3, 2)));
+        assert!(!is_visible(&grid, Point::new(3, 3)));
+    }
+
+    #[test]
+    fn test_count_visible() {
+        assert_eq!(count_visible(&example_grid()), Some(21));
+        assert_eq!(count_visible(&parse_grid(&[]).unwrap()), None);
+    }
+
+    #[test]
+    fn test_visible_trees_in_line() {
+        let grid = example_grid();
+        let point = Point::new(1, 2);
+        assert_eq!(visible_trees_in_line(grid.ray(point, LEFT), 5), 1);
+        assert_eq!(visible_trees_in_line(grid.ray(point, RIGHT), 5), 2);
+        assert_eq!(visible_trees_in_line(grid.ray(point, UP), 5), 1);
+        assert_eq!(visible_trees_in_line(grid.ray(point, DOWN), 5), 2);
+
+        let point = Point::new(3, 2);
+        assert_eq!(visible_trees_in_line(grid.ray(point, LEFT), 5), 2);
+        assert_eq!(visible_trees_in_line(grid.ray(point, RIGHT), 5), 2);
+        assert_eq!(visible_trees_in_line(grid.ray(point, UP), 5), 2);
+        assert_eq!(visible_trees_in_line(grid.ray(point, DOWN), 5), 1);
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let grid = example_grid();
+        assert_eq!(scenic_score(&grid, Point::new(1, 2)), 4);
+        assert_eq!(scenic_score(&grid, Point::new(3, 2)), 8);
+    }
+}
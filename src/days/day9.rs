@@ -1,5 +1,8 @@
+use crate::solution::Solution;
+use crate::AocError;
 use std::collections::HashMap;
-use utilities::read_input;
+
+pub struct Day9;
 
 #[derive(PartialEq, Debug, Clone)]
 enum Direction {
@@ -19,7 +22,7 @@ fn direction_to_offset(input: &Direction) -> (i32, i32) {
 }
 
 #[derive(PartialEq, Debug, Clone)]
-struct Motion {
+pub struct Motion {
     direction: Direction,
     distance: usize,
 }
@@ -34,18 +37,21 @@ fn parse_direction(input: &str) -> Option<Direction> {
     }
 }
 
-fn parse_motion<T>(line: T) -> Option<Motion>
+fn parse_motion<T>(line: T) -> Result<Motion, AocError>
 where
     T: Into<String>,
 {
     let line_string: String = line.into();
-    let mut split = line_string.split(" ");
-    let direction = parse_direction(split.next()?)?;
+    let mut split = line_string.split(' ');
+    let direction = split
+        .next()
+        .and_then(parse_direction)
+        .ok_or_else(|| AocError::parse(line_string.as_str(), "one of `U`, `D`, `L` or `R`"))?;
     let distance = split
-        .next()?
-        .parse::<usize>()
-        .expect("Couldn't parse distance!");
-    Some(Motion {
+        .next()
+        .and_then(|distance| distance.parse::<usize>().ok())
+        .ok_or_else(|| AocError::parse(line_string.as_str(), "a distance").at_column(2))?;
+    Ok(Motion {
         direction,
         distance,
     })
@@ -102,7 +108,7 @@ impl Snake {
             let mut last_position = *self.head();
 
             for knot in self.knot_positions.iter_mut().skip(1) {
-                if !are_neighbors(&last_position, &knot) {
+                if !are_neighbors(&last_position, knot) {
                     *knot = add_offset(*knot, follow_direction(knot, &last_position));
                 }
                 last_position = *knot;
@@ -112,28 +118,35 @@ impl Snake {
     }
 }
 
-fn main() {
-    if let Ok(lines) = read_input("inputs/day9.txt") {
-        let motions = lines
+fn count_tail_positions(motions: &[Motion], knots: usize) -> usize {
+    let mut snake = Snake::new(knots);
+    for motion in motions {
+        snake.apply_motion(motion.clone());
+    }
+    snake.visited_positions.keys().count()
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Motion>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError> {
+        lines
             .iter()
-            .map(parse_motion)
-            .map(<Option<Motion>>::unwrap)
-            .collect::<Vec<_>>();
-        let mut snake = Snake::new(2);
-        for motion in motions.clone() {
-            snake.apply_motion(motion);
-        }
-        let visited_position_count = snake.visited_positions.keys().count();
-        println!("The tail visied {:?} positions.", visited_position_count);
+            .enumerate()
+            .map(|(index, line)| parse_motion(line).map_err(|err| err.at_line(index)))
+            .collect()
+    }
 
-        let mut snake = Snake::new(10);
-        for motion in motions {
-            snake.apply_motion(motion);
-        }
-        let visited_position_count = snake.visited_positions.keys().count();
-        println!("The tail visied {:?} positions.", visited_position_count);
-    } else {
-        println!("Unable to read input!");
+    fn part_one(motions: &Self::Input) -> Result<usize, AocError> {
+        Ok(count_tail_positions(motions, 2))
+    }
+
+    fn part_two(motions: &Self::Input) -> Result<usize, AocError> {
+        Ok(count_tail_positions(motions, 10))
     }
 }
 
@@ -145,32 +158,34 @@ mod tests {
     fn test_parse_motion() {
         assert_eq!(
             parse_motion("R 4"),
-            Some(Motion {
+            Ok(Motion {
                 direction: Direction::Right,
                 distance: 4
             })
         );
         assert_eq!(
             parse_motion("U 42"),
-            Some(Motion {
+            Ok(Motion {
                 direction: Direction::Up,
                 distance: 42
             })
         );
         assert_eq!(
             parse_motion("L 10"),
-            Some(Motion {
+            Ok(Motion {
                 direction: Direction::Left,
                 distance: 10
             })
         );
         assert_eq!(
             parse_motion("D 1"),
-            Some(Motion {
+            Ok(Motion {
                 direction: Direction::Down,
                 distance: 1
             })
         );
+        assert!(parse_motion("X 1").is_err());
+        assert!(parse_motion("D").is_err());
     }
 
     #[test]
@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors raised while reading, parsing or solving a puzzle input.
+///
+/// Parse errors are usually created by a line level parser that only knows the
+/// offending text and are then located with [`AocError::at_line`] and
+/// [`AocError::in_file`] by the code that iterates over the input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AocError {
+    Io {
+        file: String,
+        message: String,
+    },
+    Parse {
+        file: Option<String>,
+        line: Option<usize>,
+        column: Option<usize>,
+        text: String,
+        expected: String,
+    },
+    UnexpectedEnd {
+        file: Option<String>,
+        expected: String,
+    },
+    Solve(String),
+}
+
+impl AocError {
+    pub fn parse<T, E>(text: T, expected: E) -> AocError
+    where
+        T: Into<String>,
+        E: Into<String>,
+    {
+        AocError::Parse {
+            file: None,
+            line: None,
+            column: None,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn unexpected_end<E>(expected: E) -> AocError
+    where
+        E: Into<String>,
+    {
+        AocError::UnexpectedEnd {
+            file: None,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn solve<M>(message: M) -> AocError
+    where
+        M: Into<String>,
+    {
+        AocError::Solve(message.into())
+    }
+
+    /// Sets the zero based line index of a parse error, unless it is already known.
+    pub fn at_line(mut self, line_index: usize) -> AocError {
+        if let AocError::Parse { line, .. } = &mut self {
+            line.get_or_insert(line_index + 1);
+        }
+        self
+    }
+
+    /// Sets the zero based column index of a parse error, unless it is already known.
+    pub fn at_column(mut self, column_index: usize) -> AocError {
+        if let AocError::Parse { column, .. } = &mut self {
+            column.get_or_insert(column_index + 1);
+        }
+        self
+    }
+
+    /// Attributes a parse error to the given input file, unless it is already known.
+    pub fn in_file<F>(mut self, file_name: F) -> AocError
+    where
+        F: Into<String>,
+    {
+        match &mut self {
+            AocError::Parse { file, .. } | AocError::UnexpectedEnd { file, .. } => {
+                file.get_or_insert(file_name.into());
+            }
+            AocError::Io { .. } | AocError::Solve(_) => (),
+        }
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { file, message } => write!(f, "{}: {}", file, message),
+            AocError::Parse {
+                file,
+                line,
+                column,
+                text,
+                expected,
+            } => {
+                let location = [
+                    file.clone(),
+                    line.map(|line| format!("line {}", line)),
+                    column.map(|column| format!("column {}", column)),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+                if !location.is_empty() {
+                    write!(f, "{}: ", location.join(" "))?;
+                }
+                write!(f, "expected {}, found `{}`", expected, text)
+            }
+            AocError::UnexpectedEnd { file, expected } => {
+                if let Some(file) = file {
+                    write!(f, "{}: ", file)?;
+                }
+                write!(f, "unexpected end of input, expected {}", expected)
+            }
+            AocError::Solve(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_parse_error() {
+        let error = AocError::parse("  Test: divisable by 3", "`Test: divisible by N`");
+        assert_eq!(
+            error.to_string(),
+            "expected `Test: divisible by N`, found `  Test: divisable by 3`"
+        );
+        assert_eq!(
+            error.at_line(6).in_file("day11").to_string(),
+            "day11 line 7: expected `Test: divisible by N`, found `  Test: divisable by 3`"
+        );
+        assert_eq!(
+            AocError::parse("x", "a digit")
+                .at_column(2)
+                .at_line(0)
+                .to_string(),
+            "line 1 column 3: expected a digit, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_location_is_not_overwritten() {
+        let error = AocError::parse("x", "a digit")
+            .at_line(3)
+            .at_line(7)
+            .in_file("a.txt")
+            .in_file("b.txt");
+        assert_eq!(
+            error.to_string(),
+            "a.txt line 4: expected a digit, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_display_unexpected_end() {
+        assert_eq!(
+            AocError::unexpected_end("`If false: throw to monkey N`")
+                .in_file("day11")
+                .to_string(),
+            "day11: unexpected end of input, expected `If false: throw to monkey N`"
+        );
+    }
+}
@@ -0,0 +1,438 @@
+use crate::AocError;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::{Index, IndexMut};
+
+/// A cell position, counted from the top left corner.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Point {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Point {
+    pub fn new(row: usize, column: usize) -> Point {
+        Point { row, column }
+    }
+
+    /// Moves the point by a `(row, column)` offset, unless that leaves the first quadrant.
+    pub fn offset(&self, (row, column): Direction) -> Option<Point> {
+        Some(Point {
+            row: self.row.checked_add_signed(row)?,
+            column: self.column.checked_add_signed(column)?,
+        })
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+}
+
+/// A step as `(row, column)` offset.
+pub type Direction = (isize, isize);
+
+pub const UP: Direction = (-1, 0);
+pub const LEFT: Direction = (0, -1);
+pub const RIGHT: Direction = (0, 1);
+pub const DOWN: Direction = (1, 0);
+
+/// The four orthogonal directions, in reading order of the neighbours they lead to.
+pub const DIRECTIONS_4: [Direction; 4] = [UP, LEFT, RIGHT, DOWN];
+
+/// All eight directions including diagonals, in reading order of the neighbours they lead to.
+pub const DIRECTIONS_8: [Direction; 8] =
+    [(-1, -1), UP, (-1, 1), LEFT, RIGHT, (1, -1), DOWN, (1, 1)];
+
+/// A rectangular grid of cells stored in row major order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which have to be of equal length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one cell per character, reporting the position of characters that `parse`
+    /// rejects as well as rows that are shorter or longer than the first one.
+    pub fn parse<F>(lines: &[String], expected: &str, mut parse: F) -> Result<Grid<T>, AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut rows = Vec::with_capacity(lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(column, char)| {
+                    parse(char).ok_or_else(|| {
+                        AocError::parse(line, expected)
+                            .at_line(index)
+                            .at_column(column)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if rows
+                .first()
+                .is_some_and(|first: &Vec<T>| first.len() != row.len())
+            {
+                return Err(AocError::parse(line, "rows of equal length").at_line(index));
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).expect("Rows have been checked to be of equal length."))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.column < self.width
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then_some(point.row * self.width + point.column)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(self.index_of(point)?)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        let index = self.index_of(point)?;
+        self.cells.get_mut(index)
+    }
+
+    /// All points of the grid in row major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Point::new(row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Finds the first point in row major order whose cell matches `predicate`.
+    pub fn position<P>(&self, mut predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The neighbours of `point` in the given directions that lie inside the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .filter_map(move |direction| point.offset(*direction))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    pub fn neighbours_4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &DIRECTIONS_4)
+    }
+
+    pub fn neighbours_8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &DIRECTIONS_8)
+    }
+
+    /// The cells from `start` (inclusive) in `direction` up to the edge of the grid.
+    pub fn line(&self, start: Point, direction: Direction) -> Line<'_, T> {
+        let steps_to_edge = |position: usize, step: isize, size: usize| match step {
+            0 => usize::MAX,
+            step if step > 0 => (size - position - 1) / step as usize,
+            step => position / step.unsigned_abs(),
+        };
+        let length = match self.contains(start) {
+            true => steps_to_edge(start.row, direction.0, self.height)
+                .min(steps_to_edge(start.column, direction.1, self.width))
+                .saturating_add(1),
+            false => 0,
+        };
+        Line {
+            grid: self,
+            start,
+            direction,
+            front: 0,
+            back: if direction == (0, 0) {
+                length.min(1)
+            } else {
+                length
+            },
+        }
+    }
+
+    /// The cells seen when looking from `start` in `direction`, excluding `start` itself.
+    pub fn ray(&self, start: Point, direction: Direction) -> Line<'_, T> {
+        let mut line = self.line(start, direction);
+        line.next();
+        line
+    }
+
+    pub fn row(&self, row: usize) -> Line<'_, T> {
+        self.line(Point::new(row, 0), RIGHT)
+    }
+
+    pub fn column(&self, column: usize) -> Line<'_, T> {
+        self.line(Point::new(0, column), DOWN)
+    }
+
+    /// The diagonal running from `start` towards the bottom right.
+    pub fn diagonal(&self, start: Point) -> Line<'_, T> {
+        self.line(start, (1, 1))
+    }
+
+    /// The diagonal running from `start` towards the bottom left.
+    pub fn anti_diagonal(&self, start: Point) -> Line<'_, T> {
+        self.line(start, (1, -1))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = Line<'_, T>> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = Line<'_, T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("Point is outside of the grid!")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).expect("Point is outside of the grid!")
+    }
+}
+
+/// Renders one line per row, without a trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A straight line of cells, e.g. a row, a column or a diagonal.
+#[derive(Debug)]
+pub struct Line<'a, T> {
+    grid: &'a Grid<T>,
+    start: Point,
+    direction: Direction,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Clone for Line<'a, T> {
+    fn clone(&self) -> Self {
+        Line { ..*self }
+    }
+}
+
+impl<'a, T> Line<'a, T> {
+    fn point(&self, step: usize) -> Point {
+        let step = step as isize;
+        self.start
+            .offset((self.direction.0 * step, self.direction.1 * step))
+            .expect("Lines never leave the grid.")
+    }
+}
+
+impl<'a, T> Iterator for Line<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(&self.grid[self.point(self.front - 1)])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Line<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(&self.grid[self.point(self.back)])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Line<'a, T> {}
+
+impl<'a, T> FusedIterator for Line<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u32> {
+        let lines = ["123", "456"].map(str::to_string);
+        Grid::parse(&lines, "a digit", |char| char.to_digit(10)).unwrap()
+    }
+
+    fn collect<'a>(line: impl Iterator<Item = &'a u32>) -> Vec<u32> {
+        line.copied().collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 0)], 4);
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(
+            Grid::parse(&["12".to_string(), "3x".to_string()], "a digit", |char| {
+                char.to_digit(10)
+            })
+            .map_err(|err| err.to_string()),
+            Err("line 2 column 2: expected a digit, found `3x`".to_string())
+        );
+        assert!(
+            Grid::parse(&["12".to_string(), "3".to_string()], "a digit", |char| {
+                char.to_digit(10)
+            })
+            .is_err()
+        );
+        assert_eq!(Grid::from_rows(vec![vec![1], vec![]]), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours_4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours_8(Point::new(1, 1)).collect::<Vec<_>>(),
+            vec![
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(1, 0),
+                Point::new(1, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        assert_eq!(collect(grid.row(1)), vec![4, 5, 6]);
+        assert_eq!(collect(grid.row(1).rev()), vec![6, 5, 4]);
+        assert_eq!(collect(grid.column(2)), vec![3, 6]);
+        assert_eq!(collect(grid.column(2).rev()), vec![6, 3]);
+        assert_eq!(collect(grid.diagonal(Point::new(0, 1))), vec![2, 6]);
+        assert_eq!(collect(grid.anti_diagonal(Point::new(0, 1))), vec![2, 4]);
+        assert_eq!(collect(grid.ray(Point::new(1, 2), LEFT)), vec![5, 4]);
+        assert_eq!(collect(grid.ray(Point::new(1, 2), DOWN)), vec![]);
+        assert_eq!(grid.row(0).len(), 3);
+        assert_eq!(grid.row(2).len(), 0);
+
+        let mut line = grid.row(0);
+        assert_eq!(line.next(), Some(&1));
+        assert_eq!(line.next_back(), Some(&3));
+        assert_eq!(line.next(), Some(&2));
+        assert_eq!(line.next_back(), None);
+    }
+
+    #[test]
+    fn test_transpose_and_display() {
+        let grid = grid();
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(
+            grid.columns().rev().map(collect).collect::<Vec<_>>(),
+            vec![vec![3, 6], vec![2, 5], vec![1, 4]]
+        );
+    }
+
+    #[test]
+    fn test_position_and_map() {
+        let grid = grid();
+        assert_eq!(grid.position(|value| *value == 5), Some(Point::new(1, 1)));
+        assert_eq!(grid.map(|value| value * 2).to_string(), "246\n81012");
+    }
+}
@@ -0,0 +1,386 @@
+use crate::AocError;
+use std::env;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that points to the directory holding the `dayN.txt` files.
+pub const INPUT_ROOT_VARIABLE: &str = "AOC_INPUT_ROOT";
+const DEFAULT_INPUT_ROOT: &str = "inputs";
+const STDIN_NAME: &str = "<stdin>";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum InputKind {
+    #[default]
+    Real,
+    Example,
+}
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    /// `dayN.txt` or `dayN-example.txt` inside the given directory.
+    Root {
+        root: PathBuf,
+        kind: InputKind,
+    },
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads from the input root given on the command line, [`INPUT_ROOT_VARIABLE`] or
+    /// `inputs`, in that order.
+    pub fn from_root(root: Option<PathBuf>, kind: InputKind) -> InputSource {
+        InputSource::Root {
+            root: resolve_input_root(root, env::var_os(INPUT_ROOT_VARIABLE)),
+            kind,
+        }
+    }
+
+    /// Interprets a path given on the command line, where `-` stands for stdin.
+    pub fn from_argument(argument: &str) -> InputSource {
+        match argument {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            InputSource::Root { root, kind } => {
+                day_input_path(root, day, *kind).display().to_string()
+            }
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => STDIN_NAME.to_string(),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<Vec<String>, AocError> {
+        match self {
+            InputSource::Root { root, kind } => read_input(day_input_path(root, day, *kind)),
+            InputSource::File(path) => read_input(path),
+            InputSource::Stdin => read_lines(io::stdin(), STDIN_NAME).collect(),
+        }
+    }
+}
+
+/// The command line options that choose the input, shared by all commands.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct InputOptions {
+    pub root: Option<PathBuf>,
+    pub kind: InputKind,
+    pub input: Option<InputSource>,
+}
+
+impl InputOptions {
+    pub const USAGE: &'static str =
+        "Input options: [--example] [--input-root <dir>] [--input <path|->]
+
+The input of day N is read from <dir>/dayN.txt, or <dir>/dayN-example.txt with --example.
+<dir> defaults to $AOC_INPUT_ROOT or `inputs`. --input reads a single day from a file or
+from stdin if the path is `-`, which is solved like a real input unless --example is given.";
+
+    /// Applies `argument` if it is an input option, taking its value from `rest`.
+    ///
+    /// Returns whether `argument` was an input option.
+    pub fn parse_argument<'a, I>(&mut self, argument: &str, rest: &mut I) -> Result<bool, String>
+    where
+        I: Iterator<Item = &'a String>,
+    {
+        match argument {
+            "--example" => self.kind = InputKind::Example,
+            "--input-root" => {
+                let root = rest
+                    .next()
+                    .ok_or_else(|| "--input-root requires a directory.".to_string())?;
+                self.root = Some(PathBuf::from(root));
+            }
+            "--input" => {
+                let path = rest
+                    .next()
+                    .ok_or_else(|| "--input requires a path.".to_string())?;
+                self.input = Some(InputSource::from_argument(path));
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Checks that the options don't contradict each other once all arguments are parsed.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.input.is_some() && self.root.is_some() {
+            return Err("--input cannot be combined with --input-root.".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn source(&self) -> InputSource {
+        match &self.input {
+            Some(input) => input.clone(),
+            None => InputSource::from_root(self.root.clone(), self.kind),
+        }
+    }
+}
+
+fn resolve_input_root(root: Option<PathBuf>, variable: Option<OsString>) -> PathBuf {
+    root.or_else(|| variable.map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_ROOT))
+}
+
+pub fn day_input_path(root: &Path, day: u8, kind: InputKind) -> PathBuf {
+    match kind {
+        InputKind::Real => root.join(format!("day{}.txt", day)),
+        InputKind::Example => root.join(format!("day{}-example.txt", day)),
+    }
+}
+
+pub fn read_input<P>(file_name: P) -> Result<Vec<String>, AocError>
+where
+    P: AsRef<Path>,
+{
+    let name = file_name.as_ref().display().to_string();
+    let file = File::open(&file_name).map_err(|err| io_error(&name, err))?;
+    read_lines(file, name).collect()
+}
+
+fn io_error(name: &str, err: io::Error) -> AocError {
+    AocError::Io {
+        file: name.to_string(),
+        message: err.to_string(),
+    }
+}
+
+/// Streams the lines of any reader, e.g. a file or `io::stdin()`.
+///
+/// `name` is only used to attribute read errors.
+pub fn read_lines<R, N>(reader: R, name: N) -> LineReader<R>
+where
+    R: Read,
+    N: Into<String>,
+{
+    LineReader {
+        lines: BufReader::new(reader).lines(),
+        name: name.into(),
+    }
+}
+
+pub struct LineReader<R> {
+    lines: io::Lines<BufReader<R>>,
+    name: String,
+}
+
+impl<R: Read> Iterator for LineReader<R> {
+    type Item = Result<String, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.lines.next()?.map_err(|err| io_error(&self.name, err)))
+    }
+}
+
+/// A group of consecutive lines that remembers where in the input it started.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Record<'a> {
+    pub first_line_index: usize,
+    pub lines: &'a [String],
+}
+
+impl<'a> Record<'a> {
+    pub fn new(first_line_index: usize, lines: &'a [String]) -> Record<'a> {
+        Record {
+            first_line_index,
+            lines,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Iterates over the lines together with their index in the whole input.
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, &'a String)> {
+        let first_line_index = self.first_line_index;
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(index, line)| (first_line_index + index, line))
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Splits the input into blank line separated records.
+///
+/// Runs of blank lines count as a single separator and the last record does not need a
+/// trailing blank line.
+pub fn records(lines: &[String]) -> Records<'_> {
+    Records { lines, position: 0 }
+}
+
+pub struct Records<'a> {
+    lines: &'a [String],
+    position: usize,
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = &self.lines[self.position..];
+        let start = self.position + remaining.iter().position(|line| !is_blank(line))?;
+        let length = self.lines[start..]
+            .iter()
+            .position(|line| is_blank(line))
+            .unwrap_or(self.lines.len() - start);
+        self.position = start + length;
+        Some(Record::new(start, &self.lines[start..self.position]))
+    }
+}
+
+/// Splits the input at its first blank line into a header and a body section.
+///
+/// The body is empty if there is no blank line.
+pub fn header_and_body(lines: &[String]) -> (Record<'_>, Record<'_>) {
+    match lines.iter().position(|line| is_blank(line)) {
+        Some(separator) => (
+            Record::new(0, &lines[..separator]),
+            Record::new(separator + 1, &lines[separator + 1..]),
+        ),
+        None => (
+            Record::new(0, lines),
+            Record::new(lines.len(), &lines[lines.len()..]),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &str) -> Vec<String> {
+        read_lines(input.as_bytes(), "test")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_read_lines() {
+        assert_eq!(lines("a\nb\n"), vec!["a", "b"]);
+        assert_eq!(lines("a\n\nb"), vec!["a", "", "b"]);
+        assert!(lines("").is_empty());
+    }
+
+    #[test]
+    fn test_read_input_missing_file() {
+        assert!(matches!(
+            read_input("inputs/missing.txt"),
+            Err(AocError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_input_root() {
+        assert_eq!(resolve_input_root(None, None), PathBuf::from("inputs"));
+        assert_eq!(
+            resolve_input_root(None, Some("/aoc".into())),
+            PathBuf::from("/aoc")
+        );
+        assert_eq!(
+            resolve_input_root(Some("data".into()), Some("/aoc".into())),
+            PathBuf::from("data")
+        );
+    }
+
+    #[test]
+    fn test_input_source() {
+        let root = PathBuf::from("inputs");
+        assert_eq!(
+            day_input_path(&root, 11, InputKind::Example),
+            root.join("day11-example.txt")
+        );
+        let source = InputSource::Root {
+            root,
+            kind: InputKind::Real,
+        };
+        assert_eq!(source.read(1), read_input("inputs/day1.txt"));
+        assert_eq!(InputSource::from_argument("-"), InputSource::Stdin);
+        assert_eq!(InputSource::from_argument("-").name(3), "<stdin>");
+        assert_eq!(
+            InputSource::from_argument("a.txt"),
+            InputSource::File(PathBuf::from("a.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_options() {
+        let arguments = ["--example", "--input-root", "data", "--part", "1"].map(String::from);
+        let mut arguments = arguments.iter();
+        let mut options = InputOptions::default();
+        let mut others = Vec::new();
+        while let Some(argument) = arguments.next() {
+            if !options.parse_argument(argument, &mut arguments).unwrap() {
+                others.push(argument);
+            }
+        }
+        assert_eq!(others, ["--part", "1"]);
+        assert_eq!(options.validate(), Ok(()));
+        assert_eq!(
+            options.source(),
+            InputSource::Root {
+                root: PathBuf::from("data"),
+                kind: InputKind::Example
+            }
+        );
+
+        let stdin = [String::from("-")];
+        assert_eq!(
+            options.parse_argument("--input", &mut stdin.iter()),
+            Ok(true)
+        );
+        assert_eq!(options.source(), InputSource::Stdin);
+        assert!(options.validate().is_err());
+        assert!(options.parse_argument("--input", &mut [].iter()).is_err());
+    }
+
+    #[test]
+    fn test_records() {
+        let input = lines("1\n2\n\n3\n\n\n4\n5");
+        let records = records(&input).collect::<Vec<_>>();
+        assert_eq!(
+            records,
+            vec![
+                Record::new(0, &input[0..2]),
+                Record::new(3, &input[3..4]),
+                Record::new(6, &input[6..8]),
+            ]
+        );
+        assert_eq!(
+            records[2].enumerate().collect::<Vec<_>>(),
+            vec![(6, &input[6]), (7, &input[7])]
+        );
+
+        let input = lines("\n1\n2\n\n");
+        assert_eq!(
+            super::records(&input).collect::<Vec<_>>(),
+            vec![Record::new(1, &input[1..3])]
+        );
+        assert_eq!(super::records(&[]).count(), 0);
+    }
+
+    #[test]
+    fn test_header_and_body() {
+        let input = lines("a\nb\n\nc\n\nd");
+        let (header, body) = header_and_body(&input);
+        assert_eq!(header, Record::new(0, &input[0..2]));
+        assert_eq!(body, Record::new(3, &input[3..6]));
+
+        let input = lines("a\nb");
+        let (header, body) = header_and_body(&input);
+        assert_eq!(header, Record::new(0, &input[..]));
+        assert!(body.is_empty());
+        assert_eq!(body.first_line_index, 2);
+    }
+}
@@ -0,0 +1,365 @@
+use crate::number_theory::Integer;
+use std::cmp::{max, min};
+use std::fmt;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// Integer types that can be used as interval bounds.
+pub trait Bound: Integer + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Integer + Add<Output = T> + Sub<Output = T>> Bound for T {}
+
+/// A non-empty interval of integers that includes both of its bounds.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Bound> Interval<T> {
+    /// The interval from `start` to `end` inclusive, if it is not empty.
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn inclusive(range: RangeInclusive<T>) -> Option<Interval<T>> {
+        let (start, end) = range.into_inner();
+        Interval::new(start, end)
+    }
+
+    pub fn exclusive(range: Range<T>) -> Option<Interval<T>> {
+        // `range.end - 1` can't underflow if the range isn't empty:
+        (range.start < range.end).then(|| Interval {
+            start: range.start,
+            end: range.end - T::ONE,
+        })
+    }
+
+    pub fn single(value: T) -> Interval<T> {
+        Interval {
+            start: value,
+            end: value,
+        }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// The number of values in the interval, or `None` if that doesn't fit into `T`, e.g.
+    /// for all values of `u8`.
+    pub fn len(&self) -> Option<T> {
+        self.end.checked_sub(self.start)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(max(self.start, other.start), min(self.end, other.end))
+    }
+
+    /// Whether both intervals overlap or `other` continues right after `self`, so that
+    /// their union is an interval again.
+    fn touches(&self, other: &Interval<T>) -> bool {
+        self.overlaps(other)
+            || (self.end < other.start && self.end + T::ONE == other.start)
+            || (other.end < self.start && other.end + T::ONE == self.start)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Bound> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The disjoint intervals of the set in ascending order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// The total number of values in the set, or `None` if that doesn't fit into `T`.
+    pub fn len(&self) -> Option<T> {
+        self.intervals.iter().try_fold(T::ZERO, |total, interval| {
+            total.checked_add(interval.len()?)
+        })
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // intervals before this index end too early to be merged with `interval`:
+        let first = self.intervals.partition_point(|existing| {
+            existing.end < interval.start && !existing.touches(&interval)
+        });
+        let mut merged = interval;
+        let mut last = first;
+        while let Some(existing) = self.intervals.get(last) {
+            if !existing.touches(&merged) {
+                break;
+            }
+            merged.start = min(merged.start, existing.start);
+            merged.end = max(merged.end, existing.end);
+            last += 1;
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        *self = self.difference(&IntervalSet::from(interval));
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.containing(value).is_some()
+    }
+
+    /// The interval of the set that `value` belongs to.
+    pub fn containing(&self, value: T) -> Option<&Interval<T>> {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+        self.intervals
+            .get(index)
+            .filter(|interval| interval.contains(value))
+    }
+
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        self.containing(interval.start)
+            .is_some_and(|containing| containing.contains_interval(interval))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut left, mut right) = (self.intervals.iter(), other.intervals.iter());
+        let (mut a, mut b) = (left.next(), right.next());
+        while let (Some(first), Some(second)) = (a, b) {
+            intervals.extend(first.intersection(second));
+            if first.end < second.end {
+                a = left.next();
+            } else {
+                b = right.next();
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// All values of `self` that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        // both sets are sorted, so intervals of `other` that end before an interval of
+        // `self` can't affect the intervals of `self` after it either:
+        let mut first = 0;
+        for interval in &self.intervals {
+            while other
+                .intervals
+                .get(first)
+                .is_some_and(|removed| removed.end < interval.start)
+            {
+                first += 1;
+            }
+            let mut rest = Some(*interval);
+            for removed in &other.intervals[first..] {
+                let Some(current) = rest else { break };
+                if current.end < removed.start {
+                    break;
+                }
+                if current.start < removed.start {
+                    intervals.push(Interval {
+                        start: current.start,
+                        end: removed.start - T::ONE,
+                    });
+                }
+                rest = (removed.end < current.end).then(|| Interval {
+                    start: removed.end + T::ONE,
+                    end: current.end,
+                });
+            }
+            intervals.extend(rest);
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The intervals between consecutive intervals of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.windows(2).map(|pair| Interval {
+            start: pair[0].end + T::ONE,
+            end: pair[1].start - T::ONE,
+        })
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut intervals = iter.into_iter().collect::<Vec<_>>();
+        intervals.sort_by_key(|interval| interval.start);
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            match set.intervals.last_mut() {
+                Some(last) if last.touches(&interval) => last.end = max(last.end, interval.end),
+                _ => set.intervals.push(interval),
+            }
+        }
+        set
+    }
+}
+
+impl<T: Bound> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|(start, end)| interval(*start, *end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(Interval::exclusive(2..5), Interval::inclusive(2..=4));
+        assert_eq!(Interval::exclusive(5..5), None);
+        assert_eq!(Interval::new(5, 4), None);
+        assert_eq!(Interval::exclusive(0u32..1), Some(Interval::single(0)));
+        assert_eq!(interval(-2, 2).len(), Some(5));
+        assert_eq!(Interval::new(0u8, 254).unwrap().len(), Some(255));
+        assert_eq!(Interval::new(0u8, 255).unwrap().len(), None);
+        assert_eq!(Interval::new(-128i8, 127).unwrap().len(), None);
+        assert!(interval(2, 8).contains_interval(&interval(3, 7)));
+        assert!(!interval(3, 7).contains_interval(&interval(2, 8)));
+        assert!(interval(5, 7).overlaps(&interval(7, 9)));
+        assert!(!interval(2, 4).overlaps(&interval(5, 8)));
+        assert_eq!(
+            interval(5, 7).intersection(&interval(7, 9)),
+            Some(interval(7, 7))
+        );
+        assert_eq!(interval(2, 4).intersection(&interval(6, 8)), None);
+        assert_eq!(interval(2, 4).to_string(), "2..=4");
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut intervals = set(&[(0, 1), (10, 12)]);
+        intervals.insert(interval(3, 4));
+        assert_eq!(intervals, set(&[(0, 1), (3, 4), (10, 12)]));
+        intervals.insert(interval(2, 2));
+        assert_eq!(intervals, set(&[(0, 4), (10, 12)]));
+        intervals.insert(interval(-5, 20));
+        assert_eq!(intervals.intervals(), [interval(-5, 20)]);
+        assert_eq!(
+            set(&[(5, 6), (0, 1), (2, 3), (8, 9)]).intervals(),
+            [interval(0, 3), interval(5, 6), interval(8, 9)]
+        );
+
+        let mut bounded = IntervalSet::from(Interval::single(u8::MAX));
+        bounded.insert(Interval::single(0));
+        assert_eq!(bounded.len(), Some(2));
+        bounded.insert(Interval::new(100, 200).unwrap());
+        assert_eq!(bounded.len(), Some(103));
+        bounded.insert(Interval::new(1, 254).unwrap());
+        assert_eq!(bounded.len(), None);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let first = set(&[(0, 5), (10, 15)]);
+        let second = set(&[(3, 11), (14, 20)]);
+        assert_eq!(first.union(&second), set(&[(0, 20)]));
+        assert_eq!(
+            first.intersection(&second),
+            set(&[(3, 5), (10, 11), (14, 15)])
+        );
+        assert_eq!(first.difference(&second), set(&[(0, 2), (12, 13)]));
+        assert_eq!(second.difference(&first), set(&[(6, 9), (16, 20)]));
+        assert!(first.difference(&first).is_empty());
+        assert_eq!(
+            set(&[(0, 1), (3, 4), (6, 7), (9, 10)]).difference(&set(&[(-3, -2), (1, 9)])),
+            set(&[(0, 0), (10, 10)])
+        );
+        assert_eq!(
+            set(&[(0, 10), (20, 30)]).difference(&set(&[(2, 3), (5, 6), (25, 40)])),
+            set(&[(0, 1), (4, 4), (7, 10), (20, 24)])
+        );
+
+        let mut removed = first.clone();
+        removed.remove(interval(2, 12));
+        assert_eq!(removed, set(&[(0, 1), (13, 15)]));
+    }
+
+    #[test]
+    fn test_queries() {
+        let intervals = set(&[(0, 5), (10, 15), (20, 20)]);
+        assert_eq!(intervals.len(), Some(13));
+        assert!(intervals.contains(12));
+        assert!(!intervals.contains(7));
+        assert_eq!(intervals.containing(3), Some(&interval(0, 5)));
+        assert!(intervals.contains_interval(&interval(11, 15)));
+        assert!(!intervals.contains_interval(&interval(4, 10)));
+        assert_eq!(
+            intervals.gaps().collect::<Vec<_>>(),
+            vec![interval(6, 9), interval(16, 19)]
+        );
+        assert_eq!(IntervalSet::<i32>::new().len(), Some(0));
+    }
+}
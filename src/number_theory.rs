@@ -0,0 +1,342 @@
+use crate::AocError;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ArithmeticError {
+    Overflow,
+    InvalidModulus,
+    NotInvertible,
+    NoSolution,
+    DivisionByZero,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::Overflow => write!(f, "arithmetic overflow"),
+            ArithmeticError::InvalidModulus => write!(f, "the modulus has to be positive"),
+            ArithmeticError::NotInvertible => write!(f, "the value has no modular inverse"),
+            ArithmeticError::NoSolution => write!(f, "the congruences have no common solution"),
+            ArithmeticError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl Error for ArithmeticError {}
+
+impl From<ArithmeticError> for AocError {
+    fn from(err: ArithmeticError) -> Self {
+        AocError::solve(err.to_string())
+    }
+}
+
+/// The primitive integer types, with the operations the algorithms below need.
+pub trait Integer: Copy + Ord + fmt::Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($type:ty),*) => {
+        $(impl Integer for $type {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$type>::checked_mul(self, other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$type>::checked_div(self, other)
+            }
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$type>::checked_rem(self, other)
+            }
+
+            fn checked_neg(self) -> Option<Self> {
+                <$type>::checked_neg(self)
+            }
+        })*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Turns the `None` of a failed checked operation into an overflow error.
+pub(crate) fn overflow<T>(value: Option<T>) -> Result<T, ArithmeticError> {
+    value.ok_or(ArithmeticError::Overflow)
+}
+
+fn abs<T: Integer>(value: T) -> Result<T, ArithmeticError> {
+    match value < T::ZERO {
+        true => overflow(value.checked_neg()),
+        false => Ok(value),
+    }
+}
+
+/// The non-negative greatest common divisor, which only fails if it is the absolute value
+/// of the smallest signed integer.
+pub fn gcd<T: Integer>(a: T, b: T) -> Result<T, ArithmeticError> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        // the remainder only overflows for `MIN % -1`, which is zero:
+        let remainder = a.checked_rem(b).unwrap_or(T::ZERO);
+        (a, b) = (b, remainder);
+    }
+    abs(a)
+}
+
+/// The non-negative least common multiple, dividing before multiplying so that only a
+/// result that doesn't fit fails.
+pub fn lcm<T: Integer>(a: T, b: T) -> Result<T, ArithmeticError> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+    let divisor = gcd(a, b)?;
+    let quotient = overflow(abs(a)?.checked_div(divisor))?;
+    overflow(quotient.checked_mul(abs(b)?))
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+///
+/// The coefficients are usually negative for one of the inputs, so unsigned types only
+/// work in the few cases where they aren't.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> Result<(T, T, T), ArithmeticError> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = overflow(old_r.checked_div(r))?;
+        let step = |old: T, current: T| {
+            overflow(
+                quotient
+                    .checked_mul(current)
+                    .and_then(|product| old.checked_sub(product)),
+            )
+        };
+        (old_r, r) = (r, step(old_r, r)?);
+        (old_x, x) = (x, step(old_x, x)?);
+        (old_y, y) = (y, step(old_y, y)?);
+    }
+    if old_r < T::ZERO {
+        return Ok((
+            overflow(old_r.checked_neg())?,
+            overflow(old_x.checked_neg())?,
+            overflow(old_y.checked_neg())?,
+        ));
+    }
+    Ok((old_r, old_x, old_y))
+}
+
+fn check_modulus<T: Integer>(modulus: T) -> Result<(), ArithmeticError> {
+    match modulus > T::ZERO {
+        true => Ok(()),
+        false => Err(ArithmeticError::InvalidModulus),
+    }
+}
+
+/// The representative of `value` in `0..modulus`.
+pub fn rem_euclid<T: Integer>(value: T, modulus: T) -> Result<T, ArithmeticError> {
+    check_modulus(modulus)?;
+    let remainder = value.checked_rem(modulus).unwrap_or(T::ZERO);
+    match remainder < T::ZERO {
+        true => overflow(remainder.checked_add(modulus)),
+        false => Ok(remainder),
+    }
+}
+
+/// `(a + b) % modulus` for `a, b` in `0..modulus`, without an intermediate overflow.
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> Result<T, ArithmeticError> {
+    let room = overflow(modulus.checked_sub(b))?;
+    match a >= room {
+        true => overflow(a.checked_sub(room)),
+        false => overflow(a.checked_add(b)),
+    }
+}
+
+/// `(a - b) % modulus` for `a, b` in `0..modulus`.
+fn sub_mod<T: Integer>(a: T, b: T, modulus: T) -> Result<T, ArithmeticError> {
+    match a >= b {
+        true => overflow(a.checked_sub(b)),
+        false => add_mod(a, overflow(modulus.checked_sub(b))?, modulus),
+    }
+}
+
+/// `(a * b) % modulus` that falls back to doubling and adding if the product doesn't fit.
+pub fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> Result<T, ArithmeticError> {
+    let (a, mut b) = (rem_euclid(a, modulus)?, rem_euclid(b, modulus)?);
+    if let Some(product) = a.checked_mul(b) {
+        return rem_euclid(product, modulus);
+    }
+    let two = overflow(T::ONE.checked_add(T::ONE))?;
+    let (mut result, mut addend) = (T::ZERO, a);
+    while b > T::ZERO {
+        if overflow(b.checked_rem(two))? == T::ONE {
+            result = add_mod(result, addend, modulus)?;
+        }
+        addend = add_mod(addend, addend, modulus)?;
+        b = overflow(b.checked_div(two))?;
+    }
+    Ok(result)
+}
+
+/// The `x` in `0..modulus` with `a * x % modulus == 1`.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Result<T, ArithmeticError> {
+    // extended Euclid with the coefficients of `a` kept modulo `modulus`, which keeps them
+    // non-negative and therefore works for unsigned types as well:
+    let (mut old_r, mut r) = (rem_euclid(a, modulus)?, modulus);
+    let (mut old_s, mut s) = (rem_euclid(T::ONE, modulus)?, T::ZERO);
+    while r != T::ZERO {
+        let quotient = overflow(old_r.checked_div(r))?;
+        let remainder = overflow(old_r.checked_sub(overflow(quotient.checked_mul(r))?))?;
+        let coefficient = sub_mod(old_s, mul_mod(quotient, s, modulus)?, modulus)?;
+        (old_r, r) = (r, remainder);
+        (old_s, s) = (s, coefficient);
+    }
+    match old_r == T::ONE {
+        true => Ok(old_s),
+        false => Err(ArithmeticError::NotInvertible),
+    }
+}
+
+/// `base` to the power of `exponent` modulo `modulus`, where negative exponents use the
+/// modular inverse of `base`.
+pub fn pow_mod<T: Integer>(base: T, exponent: T, modulus: T) -> Result<T, ArithmeticError> {
+    let (mut base, mut exponent) = match exponent < T::ZERO {
+        true => (
+            mod_inverse(base, modulus)?,
+            overflow(exponent.checked_neg())?,
+        ),
+        false => (rem_euclid(base, modulus)?, exponent),
+    };
+    let two = overflow(T::ONE.checked_add(T::ONE))?;
+    let mut result = rem_euclid(T::ONE, modulus)?;
+    while exponent > T::ZERO {
+        if overflow(exponent.checked_rem(two))? == T::ONE {
+            result = mul_mod(result, base, modulus)?;
+        }
+        base = mul_mod(base, base, modulus)?;
+        exponent = overflow(exponent.checked_div(two))?;
+    }
+    Ok(result)
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for all given pairs and returns the
+/// solution as `(x, lcm of all moduli)`.
+///
+/// The moduli don't need to be coprime, but the congruences have to agree where they
+/// overlap.
+pub fn chinese_remainder<T: Integer>(congruences: &[(T, T)]) -> Result<(T, T), ArithmeticError> {
+    let mut solution = (T::ZERO, T::ONE);
+    for (residue, modulus) in congruences {
+        let (x, combined) = solution;
+        let residue = rem_euclid(*residue, *modulus)?;
+        let divisor = gcd(combined, *modulus)?;
+        // x + combined * k ≡ residue (mod modulus) has to be solved for k:
+        let difference = sub_mod(residue, rem_euclid(x, *modulus)?, *modulus)?;
+        if overflow(difference.checked_rem(divisor))? != T::ZERO {
+            return Err(ArithmeticError::NoSolution);
+        }
+        let reduced_modulus = overflow(modulus.checked_div(divisor))?;
+        let k = mul_mod(
+            overflow(difference.checked_div(divisor))?,
+            mod_inverse(overflow(combined.checked_div(divisor))?, reduced_modulus)?,
+            reduced_modulus,
+        )?;
+        let combined_modulus = lcm(combined, *modulus)?;
+        solution = (
+            overflow(x.checked_add(overflow(combined.checked_mul(k))?))?,
+            combined_modulus,
+        );
+    }
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), Ok(6));
+        assert_eq!(gcd(-12i64, 18), Ok(6));
+        assert_eq!(gcd(0u8, 7), Ok(7));
+        assert_eq!(gcd(i64::MIN, -1), Ok(1));
+        assert_eq!(gcd(i64::MIN, 0), Err(ArithmeticError::Overflow));
+        assert_eq!(lcm(4u64, 6), Ok(12));
+        assert_eq!(lcm(-4i32, 6), Ok(12));
+        assert_eq!(lcm(0u32, 6), Ok(0));
+        // the product overflows although the result fits:
+        assert_eq!(lcm(u64::MAX, u64::MAX), Ok(u64::MAX));
+        assert_eq!(lcm(u64::MAX, 2), Err(ArithmeticError::Overflow));
+        assert_eq!(lcm(1u128 << 100, 3), Ok(3 << 100));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240i64, 46), Ok((2, -9, 47)));
+        assert_eq!(extended_gcd(-240i128, 46), Ok((2, 9, 47)));
+        assert_eq!(extended_gcd(0i64, 5), Ok((5, 0, 1)));
+        assert!(extended_gcd(240u64, 46).is_err());
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3i64, 11), Ok(4));
+        assert_eq!(mod_inverse(-3i64, 11), Ok(7));
+        assert_eq!(mod_inverse(3u64, 11), Ok(4));
+        assert_eq!(mod_inverse(6u64, 9), Err(ArithmeticError::NotInvertible));
+        assert_eq!(mod_inverse(3i64, 0), Err(ArithmeticError::InvalidModulus));
+        assert_eq!(mod_inverse(3i64, -11), Err(ArithmeticError::InvalidModulus));
+        let modulus = u64::MAX - 58; // the largest 64 bit prime
+        let inverse = mod_inverse(u64::MAX / 3, modulus).unwrap();
+        assert_eq!(mul_mod(inverse, u64::MAX / 3, modulus), Ok(1));
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        assert_eq!(pow_mod(4u64, 13, 497), Ok(445));
+        assert_eq!(pow_mod(-4i64, 3, 10), Ok(6));
+        assert_eq!(pow_mod(3i64, -1, 11), Ok(4));
+        assert_eq!(pow_mod(5u32, 0, 1), Ok(0));
+        // Fermat's little theorem for a modulus close to the type's maximum:
+        let modulus = u64::MAX - 58;
+        assert_eq!(pow_mod(123456789u64, modulus - 1, modulus), Ok(1));
+        assert_eq!(mul_mod(u128::MAX - 1, u128::MAX - 1, u128::MAX), Ok(1));
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(
+            chinese_remainder(&[(2i64, 3), (3, 5), (2, 7)]),
+            Ok((23, 105))
+        );
+        assert_eq!(chinese_remainder(&[(2u64, 4), (4, 6)]), Ok((10, 12)));
+        assert_eq!(
+            chinese_remainder(&[(1u64, 4), (2, 6)]),
+            Err(ArithmeticError::NoSolution)
+        );
+        assert_eq!(chinese_remainder::<u8>(&[]), Ok((0, 1)));
+        assert_eq!(
+            chinese_remainder(&[(1u8, 16), (1, 17)]),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::number_theory::{gcd, lcm, overflow, ArithmeticError};
+use std::fmt;
+
+/// An exact fraction, always kept in lowest terms with a positive denominator.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational::integer(0);
+    pub const ONE: Rational = Rational::integer(1);
+
+    pub fn new(numerator: i128, denominator: i128) -> Result<Rational, ArithmeticError> {
+        if denominator == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        let divisor = gcd(numerator, denominator)?;
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        match denominator < 0 {
+            true => Ok(Rational {
+                numerator: overflow(numerator.checked_neg())?,
+                denominator: overflow(denominator.checked_neg())?,
+            }),
+            false => Ok(Rational {
+                numerator,
+                denominator,
+            }),
+        }
+    }
+
+    pub const fn integer(value: i128) -> Rational {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    pub fn checked_neg(self) -> Result<Rational, ArithmeticError> {
+        Ok(Rational {
+            numerator: overflow(self.numerator.checked_neg())?,
+            denominator: self.denominator,
+        })
+    }
+
+    pub fn checked_add(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        // expanding to the lcm of the denominators keeps the intermediate values small:
+        let denominator = lcm(self.denominator, other.denominator)?;
+        let expand = |value: Rational| {
+            overflow(value.numerator.checked_mul(denominator / value.denominator))
+        };
+        let numerator = overflow(expand(self)?.checked_add(expand(other)?))?;
+        Rational::new(numerator, denominator)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        // cancelling crosswise first keeps the products as small as possible:
+        let first = gcd(self.numerator, other.denominator)?.max(1);
+        let second = gcd(other.numerator, self.denominator)?.max(1);
+        let numerator = overflow((self.numerator / first).checked_mul(other.numerator / second))?;
+        let denominator =
+            overflow((self.denominator / second).checked_mul(other.denominator / first))?;
+        Rational::new(numerator, denominator)
+    }
+
+    pub fn checked_div(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        self.checked_mul(other.reciprocal()?)
+    }
+
+    pub fn reciprocal(self) -> Result<Rational, ArithmeticError> {
+        Rational::new(self.denominator, self.numerator)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::integer(value.into())
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            _ => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(rational(4, -6), rational(-2, 3));
+        assert_eq!(rational(-2, 3).denominator(), 3);
+        assert_eq!(rational(0, -5), Rational::ZERO);
+        assert_eq!(Rational::new(1, 0), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(Rational::new(1, i128::MIN), Err(ArithmeticError::Overflow));
+        assert_eq!(rational(6, 3).to_integer(), Some(2));
+        assert_eq!(rational(7, 2).to_integer(), None);
+        assert_eq!(rational(-7, 2).to_string(), "-7/2");
+        assert_eq!(Rational::from(5).to_string(), "5");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(
+            rational(1, 6).checked_add(rational(1, 3)),
+            Ok(rational(1, 2))
+        );
+        assert_eq!(
+            rational(1, 6).checked_sub(rational(1, 3)),
+            Ok(rational(-1, 6))
+        );
+        assert_eq!(
+            rational(2, 3).checked_mul(rational(9, 4)),
+            Ok(rational(3, 2))
+        );
+        assert_eq!(
+            rational(2, 3).checked_div(rational(-4, 9)),
+            Ok(rational(-3, 2))
+        );
+        assert_eq!(
+            rational(2, 3).checked_div(Rational::ZERO),
+            Err(ArithmeticError::DivisionByZero)
+        );
+        assert_eq!(
+            Rational::integer(i128::MAX).checked_add(Rational::ONE),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(
+            Rational::integer(i128::MAX)
+                .checked_mul(rational(1, i128::MAX))
+                .map(|value| value.to_integer()),
+            Ok(Some(1))
+        );
+    }
+}
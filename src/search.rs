@@ -0,0 +1,314 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The result of a successful search: the total cost and every node from start to goal.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Keeps every reached node once together with the index of its predecessor, so that
+/// queues only have to store indices.
+struct Visited<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N, C> Visited<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy,
+{
+    fn new(start: N, zero: C) -> Visited<N, C> {
+        Visited {
+            nodes: vec![(start.clone(), None, zero)],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Records `node` if it hasn't been reached yet or `is_better` than the known cost
+    /// and returns its index.
+    fn reach<F>(&mut self, node: N, parent: usize, cost: C, is_better: F) -> Option<usize>
+    where
+        F: Fn(&C) -> bool,
+    {
+        match self.indices.entry(node) {
+            Entry::Vacant(entry) => {
+                self.nodes.push((entry.key().clone(), Some(parent), cost));
+                Some(*entry.insert(self.nodes.len() - 1))
+            }
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if !is_better(&self.nodes[index].2) {
+                    return None;
+                }
+                self.nodes[index].1 = Some(parent);
+                self.nodes[index].2 = cost;
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N, C> {
+        let mut nodes = Vec::new();
+        let mut current = Some(goal);
+        while let Some(index) = current {
+            nodes.push(self.nodes[index].0.clone());
+            current = self.nodes[index].1;
+        }
+        nodes.reverse();
+        Path {
+            cost: self.nodes[goal].2,
+            nodes,
+        }
+    }
+
+    fn into_costs(self) -> HashMap<N, C> {
+        self.nodes
+            .into_iter()
+            .map(|(node, _, cost)| (node, cost))
+            .collect()
+    }
+}
+
+/// Breadth first search for the closest node that `is_goal`, counting every step as one.
+pub fn bfs<N, FN, IN, FG>(start: N, mut neighbours: FN, mut is_goal: FG) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let (node, _, distance) = &visited.nodes[index];
+        if is_goal(node) {
+            return Some(visited.path(index));
+        }
+        let distance = distance + 1;
+        for neighbour in neighbours(node) {
+            if let Some(next) = visited.reach(neighbour, index, distance, |_| false) {
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from `start` to every node that can be reached from it.
+pub fn bfs_distances<N, FN, IN>(start: N, mut neighbours: FN) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let (node, _, distance) = &visited.nodes[index];
+        let distance = distance + 1;
+        for neighbour in neighbours(node) {
+            if let Some(next) = visited.reach(neighbour, index, distance, |_| false) {
+                queue.push_back(next);
+            }
+        }
+    }
+    visited.into_costs()
+}
+
+/// An entry of the open set, ordered so that the [`BinaryHeap`] pops the lowest estimate
+/// first and prefers nodes that are further along on ties.
+struct Open<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Open<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (Reverse(&self.estimate), &self.cost).cmp(&(Reverse(&other.estimate), &other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Open<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Open<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Open<C> {}
+
+/// A* search for the cheapest path to a node that `is_goal`.
+///
+/// `successors` yields the neighbours of a node together with the cost of moving there and
+/// `heuristic` must never overestimate the remaining cost for the result to be optimal.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut open = BinaryHeap::from([Open {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: 0,
+    }]);
+    let mut visited = Visited::new(start, C::default());
+    while let Some(Open { cost, index, .. }) = open.pop() {
+        let node = &visited.nodes[index].0;
+        // a cheaper way to this node has been found after this entry was queued:
+        if cost > visited.nodes[index].2 {
+            continue;
+        }
+        if is_goal(node) {
+            return Some(visited.path(index));
+        }
+        for (successor, step_cost) in successors(node) {
+            let cost = cost + step_cost;
+            let estimate = cost + heuristic(&successor);
+            if let Some(index) = visited.reach(successor, index, cost, |known| cost < *known) {
+                open.push(Open {
+                    estimate,
+                    cost,
+                    index,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm, i.e. [`astar`] without a heuristic.
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, successors: FN, is_goal: FG) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// The cost of the cheapest path from `start` to every node that can be reached from it.
+pub fn dijkstra_distances<N, C, FN, IN>(start: N, mut successors: FN) -> HashMap<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new(start, C::default());
+    let mut open = BinaryHeap::from([Open {
+        estimate: C::default(),
+        cost: C::default(),
+        index: 0,
+    }]);
+    while let Some(Open { cost, index, .. }) = open.pop() {
+        if cost > visited.nodes[index].2 {
+            continue;
+        }
+        for (successor, step_cost) in successors(&visited.nodes[index].0) {
+            let cost = cost + step_cost;
+            if let Some(index) = visited.reach(successor, index, cost, |known| cost < *known) {
+                open.push(Open {
+                    estimate: cost,
+                    cost,
+                    index,
+                });
+            }
+        }
+    }
+    visited.into_costs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1- b -1- c
+    ///  \          |
+    ///   ---5---- d
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('d', 1)],
+            'd' => vec![('a', 5), ('c', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn neighbours(node: &char) -> Vec<char> {
+        edges(node).into_iter().map(|(node, _)| node).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        assert_eq!(
+            bfs('a', neighbours, |node| *node == 'd'),
+            Some(Path {
+                cost: 1,
+                nodes: vec!['a', 'd']
+            })
+        );
+        assert_eq!(bfs('a', neighbours, |node| *node == 'x'), None);
+        assert_eq!(
+            bfs_distances('a', neighbours),
+            HashMap::from([('a', 0), ('b', 1), ('c', 2), ('d', 1)])
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra('a', edges, |node| *node == 'd'),
+            Some(Path {
+                cost: 3,
+                nodes: vec!['a', 'b', 'c', 'd']
+            })
+        );
+        assert_eq!(
+            dijkstra_distances('d', edges),
+            HashMap::from([('a', 3), ('b', 2), ('c', 1), ('d', 0)])
+        );
+        assert_eq!(dijkstra('a', edges, |node| *node == 'x'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // walk on an open 10x10 board from one corner to the other:
+        let goal = (9, 9);
+        let successors = |&(x, y): &(i32, i32)| {
+            [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .into_iter()
+                .map(move |(dx, dy)| ((x + dx, y + dy), 1))
+                .filter(|((x, y), _)| (0..10).contains(x) && (0..10).contains(y))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+        let path = astar((0, 0), successors, heuristic, |node| *node == goal).unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&goal));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{AocError, InputKind};
+use std::any::Any;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// A puzzle solution: parses the input once and computes both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input: 'static;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError>;
+
+    /// Parses an input of the given kind. Only days whose puzzle parameters differ between
+    /// the example and the real input need to override this.
+    fn parse_kind(lines: &[String], _kind: InputKind) -> Result<Self::Input, AocError> {
+        Self::parse(lines)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AocError>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, AocError>;
+}
+
+/// Type erased view of a [`Solution`] so that all days can be kept in one registry.
+pub trait Runner {
+    fn day(&self) -> u8;
+    fn parse(&self, lines: &[String], kind: InputKind) -> Result<Box<dyn Any>, AocError>;
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String, AocError>;
+}
+
+impl<S: Solution> Runner for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, lines: &[String], kind: InputKind) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(S::parse_kind(lines, kind)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String, AocError> {
+        let input = input.downcast_ref::<S::Input>().ok_or_else(|| {
+            AocError::solve(format!("Input of day {} has an unexpected type.", S::DAY))
+        })?;
+        match part {
+            Part::One => S::part_one(input).map(|answer| answer.to_string()),
+            Part::Two => S::part_two(input).map(|answer| answer.to_string()),
+        }
+    }
+}
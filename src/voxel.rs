@@ -0,0 +1,308 @@
+use std::collections::HashSet;
+use std::ops::{Add, Sub};
+
+/// A point or offset in 3D space.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The points that share a face with this one.
+    pub fn neighbours_6(&self) -> impl Iterator<Item = Point3> + '_ {
+        OFFSETS_6.iter().map(move |offset| *self + *offset)
+    }
+
+    /// The points that share a face, an edge or a corner with this one.
+    pub fn neighbours_26(&self) -> impl Iterator<Item = Point3> + '_ {
+        offsets_26().map(move |offset| *self + offset)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// Offsets to the six face neighbours.
+pub const OFFSETS_6: [Point3; 6] = [
+    Point3::new(-1, 0, 0),
+    Point3::new(1, 0, 0),
+    Point3::new(0, -1, 0),
+    Point3::new(0, 1, 0),
+    Point3::new(0, 0, -1),
+    Point3::new(0, 0, 1),
+];
+
+/// Offsets to all 26 neighbours including edges and corners.
+pub fn offsets_26() -> impl Iterator<Item = Point3> {
+    (-1..=1)
+        .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Point3::new(x, y, z))))
+        .filter(|offset| *offset != Point3::default())
+}
+
+/// An axis aligned box that includes both its `min` and `max` corner.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct BoundingBox {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl BoundingBox {
+    /// The smallest box containing all points, if there are any.
+    pub fn from_points<'a, I>(points: I) -> Option<BoundingBox>
+    where
+        I: IntoIterator<Item = &'a Point3>,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        Some(points.fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |bounds, point| BoundingBox {
+                min: Point3::new(
+                    bounds.min.x.min(point.x),
+                    bounds.min.y.min(point.y),
+                    bounds.min.z.min(point.z),
+                ),
+                max: Point3::new(
+                    bounds.max.x.max(point.x),
+                    bounds.max.y.max(point.y),
+                    bounds.max.z.max(point.z),
+                ),
+            },
+        ))
+    }
+
+    /// Grows the box by `amount` in every direction.
+    pub fn enlarge(&self, amount: i64) -> BoundingBox {
+        let offset = Point3::new(amount, amount, amount);
+        BoundingBox {
+            min: self.min - offset,
+            max: self.max + offset,
+        }
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    /// The number of points inside the box.
+    pub fn volume(&self) -> u64 {
+        let size = self.max - self.min;
+        [size.x, size.y, size.z]
+            .iter()
+            .map(|length| (*length + 1).max(0) as u64)
+            .product()
+    }
+}
+
+/// A set of unit cubes, each identified by its position.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct VoxelSet {
+    voxels: HashSet<Point3>,
+}
+
+impl VoxelSet {
+    pub fn new() -> VoxelSet {
+        VoxelSet::default()
+    }
+
+    /// Adds a voxel and returns whether it was new.
+    pub fn insert(&mut self, voxel: Point3) -> bool {
+        self.voxels.insert(voxel)
+    }
+
+    pub fn contains(&self, voxel: &Point3) -> bool {
+        self.voxels.contains(voxel)
+    }
+
+    pub fn len(&self) -> usize {
+        self.voxels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.voxels.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Point3> {
+        self.voxels.iter()
+    }
+
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        BoundingBox::from_points(&self.voxels)
+    }
+
+    /// The number of voxel faces that don't touch another voxel of the set.
+    pub fn surface_area(&self) -> usize {
+        self.voxels
+            .iter()
+            .flat_map(|voxel| voxel.neighbours_6())
+            .filter(|neighbour| !self.contains(neighbour))
+            .count()
+    }
+}
+
+impl FromIterator<Point3> for VoxelSet {
+    fn from_iter<I: IntoIterator<Item = Point3>>(iter: I) -> Self {
+        VoxelSet {
+            voxels: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// The face between a reached cell and a neighbouring cell the fill couldn't enter.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Face {
+    pub inside: Point3,
+    pub outside: Point3,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fill {
+    pub cells: VoxelSet,
+    pub boundary: Vec<Face>,
+}
+
+/// Visits every cell inside `bounds` that can be reached from `start` through `offsets`
+/// while `is_open` holds, collecting the faces towards blocked cells on the way.
+///
+/// Faces towards cells outside of `bounds` are not part of the boundary.
+pub fn flood_fill<F>(
+    start: Point3,
+    bounds: &BoundingBox,
+    offsets: &[Point3],
+    mut is_open: F,
+) -> Fill
+where
+    F: FnMut(&Point3) -> bool,
+{
+    let mut fill = Fill {
+        cells: VoxelSet::new(),
+        boundary: Vec::new(),
+    };
+    if !bounds.contains(&start) || !is_open(&start) {
+        return fill;
+    }
+    fill.cells.insert(start);
+    let mut open = vec![start];
+    while let Some(cell) = open.pop() {
+        for offset in offsets {
+            let neighbour = cell + *offset;
+            if !bounds.contains(&neighbour) || fill.cells.contains(&neighbour) {
+                continue;
+            }
+            if is_open(&neighbour) {
+                fill.cells.insert(neighbour);
+                open.push(neighbour);
+            } else {
+                fill.boundary.push(Face {
+                    inside: cell,
+                    outside: neighbour,
+                });
+            }
+        }
+    }
+    fill
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_cubes() -> VoxelSet {
+        [Point3::new(1, 1, 1), Point3::new(2, 1, 1)]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let origin = Point3::default();
+        assert_eq!(origin.neighbours_6().count(), 6);
+        assert!(origin
+            .neighbours_6()
+            .all(|neighbour| neighbour.manhattan_distance(&origin) == 1));
+        assert_eq!(origin.neighbours_26().collect::<HashSet<_>>().len(), 26);
+        assert!(!origin.neighbours_26().any(|neighbour| neighbour == origin));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let bounds = two_cubes().bounding_box().unwrap();
+        assert_eq!(
+            bounds,
+            BoundingBox {
+                min: Point3::new(1, 1, 1),
+                max: Point3::new(2, 1, 1)
+            }
+        );
+        assert_eq!(bounds.volume(), 2);
+        assert_eq!(bounds.enlarge(1).volume(), 4 * 3 * 3);
+        assert!(bounds.contains(&Point3::new(2, 1, 1)));
+        assert!(!bounds.contains(&Point3::new(3, 1, 1)));
+        assert_eq!(VoxelSet::new().bounding_box(), None);
+    }
+
+    #[test]
+    fn test_surface_area() {
+        assert_eq!(two_cubes().surface_area(), 10);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        // a hollow 3x3x3 cube with a single air pocket in its centre:
+        let bounds = BoundingBox {
+            min: Point3::new(0, 0, 0),
+            max: Point3::new(2, 2, 2),
+        };
+        let centre = Point3::new(1, 1, 1);
+        let solid = (0..3)
+            .flat_map(|x| (0..3).flat_map(move |y| (0..3).map(move |z| Point3::new(x, y, z))))
+            .filter(|point| *point != centre)
+            .collect::<VoxelSet>();
+        assert_eq!(solid.surface_area(), 54 + 6);
+
+        let outside = bounds.enlarge(1);
+        let fill = flood_fill(outside.min, &outside, &OFFSETS_6, |cell| {
+            !solid.contains(cell)
+        });
+        assert_eq!(fill.boundary.len(), 54);
+        assert_eq!(fill.cells.len(), 5 * 5 * 5 - 27);
+        assert!(!fill.cells.contains(&centre));
+
+        let pocket = flood_fill(centre, &bounds, &OFFSETS_6, |cell| !solid.contains(cell));
+        assert_eq!(pocket.cells.len(), 1);
+        assert_eq!(pocket.boundary.len(), 6);
+
+        let blocked = flood_fill(Point3::new(0, 0, 0), &bounds, &OFFSETS_6, |cell| {
+            !solid.contains(cell)
+        });
+        assert!(blocked.cells.is_empty());
+    }
+}
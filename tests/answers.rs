@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+use utilities::answers::{escape, parse_answers, ANSWERS_FILE};
+use utilities::days;
+use utilities::solution::Part;
+use utilities::{read_input, InputKind, InputSource};
+
+const KINDS: [InputKind; 2] = [InputKind::Example, InputKind::Real];
+
+/// Runs every solution on its example and real input and compares the results with the
+/// answers file, reporting all mismatches at once.
+#[test]
+fn test_answers() {
+    let lines = read_input(ANSWERS_FILE).expect("Couldn't read answers.");
+    let answers = parse_answers(&lines)
+        .map_err(|err| err.in_file(ANSWERS_FILE))
+        .unwrap_or_else(|err| panic!("{}", err));
+
+    let mut failures = Vec::new();
+    for solution in days::all() {
+        let day = solution.day();
+        for kind in KINDS {
+            let source = InputSource::Root {
+                root: PathBuf::from("inputs"),
+                kind,
+            };
+            let input = source.read(day).and_then(|lines| {
+                solution
+                    .parse(&lines, kind)
+                    .map_err(|err| err.in_file(source.name(day)))
+            });
+            for part in Part::all() {
+                let label = format!("day {} part {} ({:?})", day, part.number(), kind);
+                let expected = answers
+                    .iter()
+                    .find(|answer| answer.day == day && answer.part == part && answer.kind == kind);
+                let Some(expected) = expected else {
+                    failures.push(format!("{}: no answer in {}", label, ANSWERS_FILE));
+                    continue;
+                };
+                let actual = input
+                    .as_ref()
+                    .map_err(Clone::clone)
+                    .and_then(|input| solution.solve(input.as_ref(), part));
+                match actual {
+                    Ok(actual) if actual == expected.answer => (),
+                    Ok(actual) => failures.push(format!(
+                        "{}: expected `{}`, got `{}`",
+                        label,
+                        escape(&expected.answer),
+                        escape(&actual)
+                    )),
+                    Err(err) => failures.push(format!("{}: {}", label, err)),
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}